@@ -0,0 +1,120 @@
+//! Compare ring multiplication against the path it replaced: a schoolbook
+//! product followed by the original long division, which inverted the
+//! divisor's leading coefficient at every step. Both are run for sparse and
+//! dense defining polynomials.
+//!
+//! Run with `cargo bench --bench reduction`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use num_bigint::BigInt;
+use rand::Rng;
+use sss_ring_extension::irreducible::{find_irreducible_polynomial, is_irreducible_mod_p};
+use sss_ring_extension::{Coefficient, GaloisRing, Polynomial};
+
+const ITERATIONS: u32 = 2000;
+
+/// A random monic polynomial of degree `degree` irreducible modulo 2, with
+/// many terms so that no sparse fast path applies.
+fn dense_irreducible(degree: usize) -> Polynomial {
+    let mut rng = rand::thread_rng();
+    loop {
+        let mut coeffs: Vec<BigInt> = (0..degree)
+            .map(|_| BigInt::from(rng.gen_range(0..2)))
+            .collect();
+        coeffs[0] = BigInt::from(1);
+        coeffs.push(BigInt::from(1));
+        let poly = Polynomial::new(coeffs);
+        if poly
+            .coeffs
+            .iter()
+            .filter(|c| **c == BigInt::from(1))
+            .count()
+            > 6
+            && is_irreducible_mod_p(&poly, &BigInt::from(2))
+        {
+            return poly;
+        }
+    }
+}
+
+/// The original long division: every step inverts the divisor's leading
+/// coefficient and subtracts a full product with a single-term quotient.
+fn original_long_division(
+    ring: &GaloisRing<u64>,
+    dividend: &Polynomial<u64>,
+    divisor: &Polynomial<u64>,
+) -> Polynomial<u64> {
+    let m = ring.coefficient_modulus();
+    let divisor = divisor.trimmed_poly();
+    let mut remainder = dividend.trimmed_poly();
+
+    while remainder.degree() >= divisor.degree() && !remainder.is_zero() {
+        let degree_diff = remainder.degree() - divisor.degree();
+        let leading_inverse = ring
+            .mod_inverse(divisor.coeffs.last().unwrap().to_bigint())
+            .unwrap();
+        let quotient_coeff = remainder
+            .coeffs
+            .last()
+            .unwrap()
+            .mul_mod(&u64::from_bigint(&leading_inverse, m), m);
+
+        let mut quotient_term = vec![0u64; degree_diff + 1];
+        quotient_term[degree_diff] = quotient_coeff;
+        let subtrahend = ring.mul(&divisor, &Polynomial::new(quotient_term));
+        remainder = ring.sub_mod(&remainder, &subtrahend);
+        remainder.trim();
+    }
+    remainder
+}
+
+/// The multiplication path before the dedicated reducer.
+fn schoolbook_then_divide(
+    ring: &GaloisRing<u64>,
+    a: &Polynomial<u64>,
+    b: &Polynomial<u64>,
+) -> Polynomial<u64> {
+    let m = ring.coefficient_modulus();
+    let mut product = vec![0u64; a.coeffs.len() + b.coeffs.len() - 1];
+    for (i, x) in a.coeffs.iter().enumerate() {
+        for (j, y) in b.coeffs.iter().enumerate() {
+            product[i + j] = product[i + j].add_mod(&x.mul_mod(y, m), m);
+        }
+    }
+    original_long_division(ring, &Polynomial::new(product), ring.irreducible())
+}
+
+fn time(mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    println!(
+        "{:>6} {:>7} {:>14} {:>14}",
+        "degree", "h", "mul_ring", "original"
+    );
+    for degree in [8, 32, 64, 128] {
+        let sparse = find_irreducible_polynomial(&BigInt::from(2), degree, false).unwrap();
+        for (kind, irreducible) in [("sparse", sparse), ("dense", dense_irreducible(degree))] {
+            let ring = GaloisRing::new(BigInt::from(1) << 64, irreducible).unwrap();
+            let ring = GaloisRing::<u64>::from_bigint_ring(&ring).unwrap();
+            let a = ring.random_ring_element();
+            let b = ring.random_ring_element();
+            assert_eq!(ring.mul_ring(&a, &b), schoolbook_then_divide(&ring, &a, &b));
+
+            let fast = time(|| {
+                black_box(ring.mul_ring(black_box(&a), black_box(&b)));
+            });
+            let slow = time(|| {
+                black_box(schoolbook_then_divide(&ring, black_box(&a), black_box(&b)));
+            });
+            println!("{:>6} {:>7} {:>14?} {:>14?}", degree, kind, fast, slow);
+        }
+    }
+}
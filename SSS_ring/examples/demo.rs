@@ -0,0 +1,41 @@
+use num_bigint::BigInt;
+use sss_ring_extension::{irreducible_polynomial, Error, GaloisRing, Polynomial};
+
+fn main() -> Result<(), Error> {
+    let modulus = BigInt::from(7);
+    let degree = 3;
+    let number_of_parties = 3;
+
+    let irreducible = irreducible_polynomial(degree).unwrap();
+    let ring = GaloisRing::new(modulus, irreducible)?;
+
+    let secret = Polynomial::new(vec![
+        BigInt::from(6), // Coefficient for x^0
+        BigInt::from(4), // Coefficient for x^1
+    ]);
+    let a1 = Polynomial::new(vec![BigInt::from(5), BigInt::from(6)]);
+    let a2 = Polynomial::new(vec![BigInt::from(0), BigInt::from(5)]);
+    let non_rand_poly = vec![secret, a1, a2];
+    println!("NON random polynomial ring: {:?}", non_rand_poly);
+
+    let shares = ring.shamir_secret_sharing_non_random(non_rand_poly, number_of_parties)?;
+    println!("shares: {:?}", shares);
+    println!(
+        "reconstructed secret: {:?}",
+        ring.reconstruct_secret(shares, 3)
+    );
+
+    let exceptional_set = ring.generate_exceptional_set()?;
+    println!("Exceptional set: {:?}", exceptional_set);
+
+    let secret = ring.random_ring_element();
+    let shares =
+        ring.shamir_secret_sharing(secret.clone(), number_of_parties, number_of_parties)?;
+    println!("shares: {:?}", shares);
+    let reconstructed_secret = ring.reconstruct_secret(shares, number_of_parties)?;
+    println!(
+        "original secret: {:?}. Reconstructed secret: {:?}",
+        secret, reconstructed_secret
+    );
+    Ok(())
+}
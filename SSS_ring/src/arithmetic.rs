@@ -0,0 +1,219 @@
+use crate::coefficient::Coefficient;
+
+/// Operand length from which [`karatsuba`] splits instead of multiplying directly.
+const KARATSUBA_THRESHOLD: usize = 24;
+
+/// Most non-zero lower terms for which `h` is reduced by sparse folding.
+const SPARSE_TERMS: usize = 4;
+
+/// Reduction modulo a fixed monic defining polynomial `h` of degree `d`.
+#[derive(Debug, Clone)]
+pub(crate) enum Reducer<C: Coefficient> {
+    /// `h = x^d + sum c_i x^i` with at most [`SPARSE_TERMS`] lower terms, as
+    /// for trinomials and pentanomials. Each coefficient at `x^j`, `j >= d`,
+    /// is folded down using `x^d = -sum c_i x^i`, costing one multiplication
+    /// per term.
+    Sparse {
+        degree: usize,
+        /// `(i, -c_i)` for the non-zero lower terms of `h`.
+        terms: Vec<(usize, C)>,
+    },
+    /// A dense `h`, reduced with the table `x^j mod h` for `d <= j < 2d - 1`,
+    /// which covers every product of two reduced elements.
+    Table { degree: usize, table: Vec<Vec<C>> },
+}
+
+impl<C: Coefficient> Reducer<C> {
+    /// The reducer for a trimmed monic `h` of positive degree.
+    pub(crate) fn new(irreducible: &[C], modulus: &C::Modulus) -> Self {
+        let degree = irreducible.len() - 1;
+        let negated: Vec<C> = irreducible[..degree]
+            .iter()
+            .map(|c| C::zero().sub_mod(c, modulus))
+            .collect();
+
+        let terms: Vec<(usize, C)> = negated
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| !c.is_zero())
+            .map(|(i, c)| (i, c.clone()))
+            .collect();
+        if terms.len() <= SPARSE_TERMS {
+            return Reducer::Sparse { degree, terms };
+        }
+
+        // x^(j+1) mod h is x * (x^j mod h) with its overflowing top coefficient folded back
+        let mut table = vec![negated.clone()];
+        for _ in 1..degree.saturating_sub(1) {
+            let previous = table.last().unwrap();
+            let top = previous[degree - 1].clone();
+            let mut next = Vec::with_capacity(degree);
+            next.push(C::zero());
+            next.extend_from_slice(&previous[..degree - 1]);
+            for (n, r) in next.iter_mut().zip(&negated) {
+                *n = n.add_mod(&top.mul_mod(r, modulus), modulus);
+            }
+            table.push(next);
+        }
+        Reducer::Table { degree, table }
+    }
+
+    /// Reduce `coeffs` modulo `h` in place, leaving at most `d` coefficients.
+    ///
+    /// Returns `false`, leaving `coeffs` untouched, if a table reducer is
+    /// given a polynomial of degree `2d - 1` or more.
+    pub(crate) fn reduce(&self, coeffs: &mut Vec<C>, modulus: &C::Modulus) -> bool {
+        match self {
+            Reducer::Sparse { degree, terms } => {
+                for j in (*degree..coeffs.len()).rev() {
+                    let top = std::mem::replace(&mut coeffs[j], C::zero());
+                    if top.is_zero() {
+                        continue;
+                    }
+                    for (i, c) in terms {
+                        let k = j - degree + i;
+                        coeffs[k] = coeffs[k].add_mod(&top.mul_mod(c, modulus), modulus);
+                    }
+                }
+                coeffs.truncate(*degree);
+            }
+            Reducer::Table { degree, table } => {
+                if coeffs.len() > degree + table.len() {
+                    return false;
+                }
+                for j in *degree..coeffs.len() {
+                    let top = std::mem::replace(&mut coeffs[j], C::zero());
+                    if top.is_zero() {
+                        continue;
+                    }
+                    for (k, r) in table[j - degree].iter().enumerate() {
+                        coeffs[k] = coeffs[k].add_mod(&top.mul_mod(r, modulus), modulus);
+                    }
+                }
+                coeffs.truncate(*degree);
+            }
+        }
+        true
+    }
+}
+
+/// The product of two coefficient vectors, by Karatsuba's method once both
+/// operands reach [`KARATSUBA_THRESHOLD`] coefficients.
+///
+/// Splitting `a = a0 + x^h a1` and `b = b0 + x^h b1` gives
+/// `ab = a0 b0 + x^h ((a0 + a1)(b0 + b1) - a0 b0 - a1 b1) + x^(2h) a1 b1`,
+/// three half-size products instead of four.
+pub(crate) fn karatsuba<C: Coefficient>(a: &[C], b: &[C], modulus: &C::Modulus) -> Vec<C> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return schoolbook(a, b, modulus);
+    }
+
+    let half = a.len().max(b.len()) / 2;
+    let (a0, a1) = a.split_at(half.min(a.len()));
+    let (b0, b1) = b.split_at(half.min(b.len()));
+    let low = karatsuba(a0, b0, modulus);
+    let high = karatsuba(a1, b1, modulus);
+    let mut middle = karatsuba(&add(a0, a1, modulus), &add(b0, b1, modulus), modulus);
+    for (i, c) in low.iter().enumerate() {
+        middle[i] = middle[i].sub_mod(c, modulus);
+    }
+    for (i, c) in high.iter().enumerate() {
+        middle[i] = middle[i].sub_mod(c, modulus);
+    }
+
+    let mut result = vec![C::zero(); a.len() + b.len() - 1];
+    for (offset, part) in [(0, &low), (half, &middle), (2 * half, &high)] {
+        for (i, c) in part.iter().enumerate() {
+            result[offset + i] = result[offset + i].add_mod(c, modulus);
+        }
+    }
+    result
+}
+
+fn schoolbook<C: Coefficient>(a: &[C], b: &[C], modulus: &C::Modulus) -> Vec<C> {
+    let mut result = vec![C::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        if x.is_zero() {
+            continue;
+        }
+        for (j, y) in b.iter().enumerate() {
+            result[i + j] = result[i + j].add_mod(&x.mul_mod(y, modulus), modulus);
+        }
+    }
+    result
+}
+
+fn add<C: Coefficient>(a: &[C], b: &[C], modulus: &C::Modulus) -> Vec<C> {
+    let zero = C::zero();
+    (0..a.len().max(b.len()))
+        .map(|i| {
+            a.get(i)
+                .unwrap_or(&zero)
+                .add_mod(b.get(i).unwrap_or(&zero), modulus)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::galois_ring::GaloisRing;
+    use crate::polynomial::Polynomial;
+    use num_bigint::BigInt;
+    use rand::rngs::StdRng;
+    use rand::{RngCore, SeedableRng};
+
+    #[test]
+    fn test_karatsuba_matches_schoolbook() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mask = u64::MAX;
+        for (n, m) in [(24, 24), (100, 37), (64, 130), (5, 200)] {
+            let a: Vec<u64> = (0..n).map(|_| rng.next_u64()).collect();
+            let b: Vec<u64> = (0..m).map(|_| rng.next_u64()).collect();
+            assert_eq!(karatsuba(&a, &b, &mask), schoolbook(&a, &b, &mask));
+        }
+    }
+
+    #[test]
+    fn test_reducers_match_long_division() {
+        let mut rng = StdRng::seed_from_u64(5);
+        // Reduction does not need h to be irreducible, only monic
+        let dense = Polynomial::new(
+            vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 1]
+                .into_iter()
+                .map(BigInt::from)
+                .collect(),
+        );
+        let sparse = Polynomial::new(
+            [1, 0, 0, 7, 0, 0, 0, 0, 0, 1]
+                .into_iter()
+                .map(BigInt::from)
+                .collect(),
+        );
+        for irreducible in [dense, sparse] {
+            let ring = GaloisRing::new_unchecked(BigInt::from(2), 16, irreducible);
+            let wide = GaloisRing::<u64>::from_bigint_ring(&ring).unwrap();
+            assert_eq!(
+                matches!(wide.reducer, Reducer::Table { .. }),
+                ring.irreducible()
+                    .coeffs
+                    .iter()
+                    .filter(|c| **c != BigInt::from(0))
+                    .count()
+                    > 5
+            );
+
+            // Products of reduced elements, and longer polynomials that need the fallback
+            for len in [1, 9, 10, 17, 30] {
+                let poly = Polynomial::new((0..len).map(|_| rng.next_u64() & 0xffff).collect());
+                let (_, expected) = wide
+                    .polynomial_long_division(&poly, wide.irreducible())
+                    .unwrap();
+                assert_eq!(wide.reduce(&poly), expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use num_bigint::BigInt;
+use rand::{CryptoRng, RngCore};
+
+use crate::coefficient::Coefficient;
+use crate::error::{check_count, Error, Result};
+use crate::galois_ring::GaloisRing;
+use crate::polynomial::Polynomial;
+use crate::sharing::{Share, SharingPlan};
+
+/// One party's shares of a multiplication triple `(a, b, ab)` with random
+/// `a` and `b`.
+///
+/// A triple must be used for at most one multiplication: opening `x - a`
+/// twice with the same `a` reveals the difference of the two inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Triple<C: Coefficient = BigInt> {
+    pub a: Share<C>,
+    pub b: Share<C>,
+    pub c: Share<C>,
+}
+
+/// The triples dealt to one party during preprocessing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preprocessing<C: Coefficient = BigInt> {
+    /// Number of shares needed to reconstruct.
+    pub threshold: usize,
+    /// Index of the party holding the triples.
+    pub party_index: usize,
+    /// The party's evaluation point, shared by all of its triples.
+    pub point: Polynomial<C>,
+    /// Unused triples, consumed from the front.
+    pub triples: Vec<Triple<C>>,
+}
+
+impl<C: Coefficient> Preprocessing<C> {
+    /// Remove and return the next `count` triples.
+    ///
+    /// Every party must take triples in the same order, so that the shares
+    /// used together belong to the same triple. Fails with
+    /// [`Error::NotEnoughTriples`] if fewer than `count` remain.
+    pub fn take_triples(&mut self, count: usize) -> Result<Vec<Triple<C>>> {
+        if count > self.triples.len() {
+            return Err(Error::NotEnoughTriples {
+                required: count,
+                available: self.triples.len(),
+            });
+        }
+        Ok(self.triples.drain(..count).collect())
+    }
+}
+
+impl<C: Coefficient> GaloisRing<C> {
+    /// The trusted dealer's preprocessing: `count` random triples shared with
+    /// threshold `t` among `n` parties at the first `n` exceptional points.
+    ///
+    /// The `i`-th returned [`Preprocessing`] goes to party `i`.
+    pub fn deal_triples(&self, count: usize, n: usize, t: usize) -> Result<Vec<Preprocessing<C>>> {
+        self.deal_triples_with_rng(count, n, t, &mut rand::thread_rng())
+    }
+
+    /// [`GaloisRing::deal_triples`] with randomness from `rng`.
+    pub fn deal_triples_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        count: usize,
+        n: usize,
+        t: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Preprocessing<C>>> {
+        let plan = SharingPlan::new(self, self.exceptional_points(n)?, t)?;
+        let mut dealt: Vec<_> = plan
+            .points()
+            .iter()
+            .enumerate()
+            .map(|(party_index, point)| Preprocessing {
+                threshold: t,
+                party_index,
+                point: point.clone(),
+                triples: Vec::with_capacity(count),
+            })
+            .collect();
+        for _ in 0..count {
+            let a = self.random_ring_element_with_rng(rng);
+            let b = self.random_ring_element_with_rng(rng);
+            let c = self.mul_ring(&a, &b);
+            let shares = plan.share_many(&[a, b, c], rng)?;
+            for (i, party) in dealt.iter_mut().enumerate() {
+                party.triples.push(Triple {
+                    a: shares[0][i].clone(),
+                    b: shares[1][i].clone(),
+                    c: shares[2][i].clone(),
+                });
+            }
+        }
+        Ok(dealt)
+    }
+
+    /// One party's first step of Beaver multiplication: its shares of
+    /// `d = x - a` and `e = y - b`, which all parties then open.
+    ///
+    /// Since `a` and `b` are uniformly random, `d` and `e` reveal nothing
+    /// about `x` and `y`.
+    pub fn beaver_masks(
+        &self,
+        x: &Share<C>,
+        y: &Share<C>,
+        triple: &Triple<C>,
+    ) -> Result<(Share<C>, Share<C>)> {
+        Ok((x.sub(&triple.a, self)?, y.sub(&triple.b, self)?))
+    }
+
+    /// One party's second step of Beaver multiplication: its share of
+    /// `xy = c + d b + e a + d e` from the opened `d` and `e`.
+    pub fn beaver_product(
+        &self,
+        triple: &Triple<C>,
+        d: &Polynomial<C>,
+        e: &Polynomial<C>,
+    ) -> Result<Share<C>> {
+        let terms = [
+            (GaloisRing::one(), triple.c.clone()),
+            (d.clone(), triple.b.clone()),
+            (e.clone(), triple.a.clone()),
+        ];
+        Share::linear_combination(self, &terms, &self.mul_ring(d, e))
+    }
+
+    /// Run Beaver multiplication for all parties at once, where `x[i]`, `y[i]`
+    /// and `triples[i]` are held by party `i`.
+    ///
+    /// This costs two openings and no communication beyond them. Fails with
+    /// [`Error::InsufficientShares`] or [`Error::WrongShareCount`] unless `y`
+    /// has as many shares as `x`, and with [`Error::NotEnoughTriples`] or
+    /// [`Error::WrongShareCount`] unless there is one triple per party.
+    pub fn beaver_multiply(
+        &self,
+        x: &[Share<C>],
+        y: &[Share<C>],
+        triples: &[Triple<C>],
+    ) -> Result<Vec<Share<C>>> {
+        check_count(x.len(), y.len())?;
+        if triples.len() < x.len() {
+            return Err(Error::NotEnoughTriples {
+                required: x.len(),
+                available: triples.len(),
+            });
+        }
+        check_count(x.len(), triples.len())?;
+        let (d_shares, e_shares): (Vec<_>, Vec<_>) = x
+            .iter()
+            .zip(y)
+            .zip(triples)
+            .map(|((xi, yi), triple)| self.beaver_masks(xi, yi, triple))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        // Every party's share takes part in both openings
+        let d = self.reconstruct_secret(d_shares, x.len())?;
+        let e = self.reconstruct_secret(e_shares, x.len())?;
+        triples
+            .iter()
+            .map(|triple| self.beaver_product(triple, &d, &e))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::irreducible::irreducible_polynomial;
+
+    #[test]
+    fn test_beaver_multiplication() {
+        let ring =
+            GaloisRing::new(BigInt::from(1) << 32, irreducible_polynomial(5).unwrap()).unwrap();
+        let narrow = GaloisRing::<u32>::from_bigint_ring(&ring).unwrap();
+        let mut dealt = narrow.deal_triples(2, 4, 2).unwrap();
+        let (x, y) = (narrow.random_ring_element(), narrow.random_ring_element());
+        let x_shares = narrow.shamir_secret_sharing(x.clone(), 4, 2).unwrap();
+        let y_shares = narrow.shamir_secret_sharing(y.clone(), 4, 2).unwrap();
+
+        let triples: Vec<_> = dealt
+            .iter_mut()
+            .map(|party| party.take_triples(1).unwrap().remove(0))
+            .collect();
+        let product = narrow
+            .beaver_multiply(&x_shares, &y_shares, &triples)
+            .unwrap();
+        let xy = narrow.mul_ring(&x, &y);
+        assert_eq!(
+            narrow.reconstruct_secret(product[2..].to_vec(), 2),
+            Ok(xy.clone())
+        );
+
+        // The second triple squares the product
+        let triples: Vec<_> = dealt
+            .iter_mut()
+            .map(|party| party.take_triples(1).unwrap().remove(0))
+            .collect();
+        let squared = narrow
+            .beaver_multiply(&product, &product, &triples)
+            .unwrap();
+        assert_eq!(
+            narrow.reconstruct_secret(squared[..2].to_vec(), 2),
+            Ok(narrow.mul_ring(&xy, &xy))
+        );
+        assert_eq!(
+            narrow.beaver_multiply(&product, &product, &triples[..3]),
+            Err(Error::NotEnoughTriples {
+                required: 4,
+                available: 3
+            })
+        );
+        assert_eq!(
+            narrow.beaver_multiply(&product[..3], &product[..3], &triples),
+            Err(Error::WrongShareCount {
+                expected: 3,
+                provided: 4
+            })
+        );
+        assert_eq!(
+            dealt[0].take_triples(1),
+            Err(Error::NotEnoughTriples {
+                required: 1,
+                available: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_dealt_triples_are_consistent() {
+        let ring = GaloisRing::from_parameters(BigInt::from(5), 3, 2).unwrap();
+        let dealt = ring.deal_triples(3, 3, 2).unwrap();
+
+        for k in 0..3 {
+            let open = |pick: fn(&Triple) -> &Share| {
+                let shares = dealt.iter().map(|party| pick(&party.triples[k]).clone());
+                ring.reconstruct_secret(shares.collect(), 2).unwrap()
+            };
+            let (a, b, c) = (open(|t| &t.a), open(|t| &t.b), open(|t| &t.c));
+            assert_eq!(ring.mul_ring(&a, &b), c);
+        }
+        assert!(dealt
+            .iter()
+            .all(|party| party.triples.iter().all(|t| t.c.point() == &party.point)));
+    }
+}
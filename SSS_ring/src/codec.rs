@@ -0,0 +1,155 @@
+use num_bigint::{BigInt, Sign};
+use num_traits::Zero;
+
+use crate::coefficient::Coefficient;
+use crate::error::{Error, Result};
+use crate::galois_ring::GaloisRing;
+use crate::polynomial::Polynomial;
+
+/// Bytes of the little-endian length that frames encoded data.
+const LENGTH_PREFIX: usize = 8;
+
+impl<C: Coefficient> GaloisRing<C> {
+    /// Number of bits one element carries in [`GaloisRing::encode_bytes`]: the
+    /// largest `b` with `2^b <= p^(kd)`.
+    ///
+    /// For `p = 2` this is all `kd` bits of the element. For odd `p` the
+    /// element is read as a `kd`-digit number in base `p^k`, which wastes
+    /// less than one bit per element.
+    pub fn bits_per_element(&self) -> u64 {
+        self.modulus.pow(self.degree() as u32).bits() - 1
+    }
+
+    /// Encode `data` as ring elements.
+    ///
+    /// The data is prefixed with its length as a little-endian `u64`, zero
+    /// padded to a multiple of [`GaloisRing::bits_per_element`] bits and cut
+    /// into chunks, each of which is written in base `p^k` as the coefficients
+    /// of one element.
+    pub fn encode_bytes(&self, data: &[u8]) -> Vec<Polynomial<C>> {
+        let mut framed = (data.len() as u64).to_le_bytes().to_vec();
+        framed.extend_from_slice(data);
+
+        let bits = self.bits_per_element() as usize;
+        let total_bits = framed.len() * 8;
+        (0..total_bits.div_ceil(bits))
+            .map(|chunk| {
+                let start = chunk * bits;
+                let end = (start + bits).min(total_bits);
+                let mut bytes = vec![0u8; (end - start).div_ceil(8)];
+                for (i, bit) in (start..end).enumerate() {
+                    bytes[i / 8] |= ((framed[bit / 8] >> (bit % 8)) & 1) << (i % 8);
+                }
+                self.element_from_integer(BigInt::from_bytes_le(Sign::Plus, &bytes))
+            })
+            .collect()
+    }
+
+    /// Decode elements produced by [`GaloisRing::encode_bytes`].
+    ///
+    /// Fails with [`Error::InvalidEncoding`] if an element is out of range,
+    /// the length prefix exceeds the data or the padding is not zero, and
+    /// with [`Error::MismatchedRing`] if an element does not belong to the ring.
+    pub fn decode_bytes(&self, elements: &[Polynomial<C>]) -> Result<Vec<u8>> {
+        let bits = self.bits_per_element();
+        let mut framed = vec![0u8; (elements.len() * bits as usize).div_ceil(8)];
+        for (chunk, elem) in elements.iter().enumerate() {
+            self.validate_element(elem)?;
+            let value = self.element_to_integer(elem);
+            if value.bits() > bits {
+                return Err(Error::InvalidEncoding(
+                    "element exceeds the encoded range".into(),
+                ));
+            }
+            for i in (0..bits).filter(|&i| value.bit(i)) {
+                let bit = chunk * bits as usize + i as usize;
+                framed[bit / 8] |= 1 << (bit % 8);
+            }
+        }
+
+        if framed.len() < LENGTH_PREFIX {
+            return Err(Error::InvalidEncoding("missing length prefix".into()));
+        }
+        let (prefix, rest) = framed.split_at(LENGTH_PREFIX);
+        let length = u64::from_le_bytes(prefix.try_into().unwrap());
+        let length = usize::try_from(length)
+            .ok()
+            .filter(|&length| length <= rest.len())
+            .ok_or_else(|| Error::InvalidEncoding("length prefix exceeds the data".into()))?;
+        let (data, padding) = rest.split_at(length);
+        let padded_elements = (framed.len() * 8 - padding.len() * 8).div_ceil(bits as usize);
+        if padding.iter().any(|&b| b != 0) || padded_elements != elements.len() {
+            return Err(Error::InvalidEncoding("invalid padding".into()));
+        }
+        Ok(data.to_vec())
+    }
+
+    /// The element whose coefficients are the base-`p^k` digits of `value`.
+    fn element_from_integer(&self, mut value: BigInt) -> Polynomial<C> {
+        let mut coeffs = Vec::with_capacity(self.degree());
+        for _ in 0..self.degree() {
+            coeffs.push(&value % &self.modulus);
+            value /= &self.modulus;
+        }
+        self.element_from_bigint(&Polynomial::new(coeffs))
+    }
+
+    /// Inverse of [`GaloisRing::element_from_integer`].
+    fn element_to_integer(&self, elem: &Polynomial<C>) -> BigInt {
+        elem.coeffs
+            .iter()
+            .rev()
+            .fold(BigInt::zero(), |acc, c| acc * &self.modulus + c.to_bigint())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::irreducible::irreducible_polynomial;
+    use crate::test_utils::ring_2_64_u64;
+
+    #[test]
+    fn test_byte_codec_round_trip() {
+        let data = b"correct horse battery staple".to_vec();
+        let wide = ring_2_64_u64();
+        let small = GaloisRing::new(BigInt::from(32), irreducible_polynomial(3).unwrap()).unwrap();
+        let odd = GaloisRing::from_parameters(BigInt::from(7), 2, 3).unwrap();
+
+        assert_eq!(wide.bits_per_element(), 256);
+        assert_eq!(small.bits_per_element(), 15);
+        // 49^3 = 117649 lies between 2^16 and 2^17
+        assert_eq!(odd.bits_per_element(), 16);
+
+        for payload in [&data[..], &[], &[0xff; 40]] {
+            let elements = wide.encode_bytes(payload);
+            assert_eq!(wide.decode_bytes(&elements).unwrap(), payload);
+            let elements = small.encode_bytes(payload);
+            assert_eq!(small.decode_bytes(&elements).unwrap(), payload);
+            let elements = odd.encode_bytes(payload);
+            assert_eq!(odd.decode_bytes(&elements).unwrap(), payload);
+        }
+        // Eight length bytes and 28 data bytes fill 288 bits, two 256-bit elements
+        assert_eq!(wide.encode_bytes(&data).len(), 2);
+    }
+
+    #[test]
+    fn test_byte_codec_rejects_tampered_elements() {
+        let ring = GaloisRing::from_parameters(BigInt::from(7), 2, 3).unwrap();
+        let mut elements = ring.encode_bytes(b"secret");
+
+        let mut extra = elements.clone();
+        extra.push(GaloisRing::zero());
+        assert!(matches!(
+            ring.decode_bytes(&extra),
+            Err(Error::InvalidEncoding(_))
+        ));
+
+        // 48^3 - 1 is a valid element but above 2^16
+        elements[0] = Polynomial::new(vec![BigInt::from(48); 3]);
+        assert!(matches!(
+            ring.decode_bytes(&elements),
+            Err(Error::InvalidEncoding(_))
+        ));
+    }
+}
@@ -0,0 +1,194 @@
+use num_bigint::{BigInt, Sign};
+use num_traits::{One, ToPrimitive, Zero};
+use rand::RngCore;
+use std::fmt::Debug;
+
+/// Integers modulo the coefficient modulus `m = p^k` of a Galois ring.
+///
+/// Each backend pairs a value type with a precomputed [`Coefficient::Modulus`]
+/// that carries whatever it needs to reduce modulo `m`. [`BigInt`] handles
+/// any modulus; `u64` and `u32` handle `m = 2^k` for `k` up to their bit
+/// width with wrapping arithmetic, where reduction is a mask (and free when
+/// `k` equals the bit width).
+pub trait Coefficient: Clone + Debug + Eq + Zero + One + Send + Sync + 'static {
+    /// Reduction data for one coefficient modulus.
+    type Modulus: Clone + Debug + PartialEq + Send + Sync;
+
+    /// Reduction data for `m`, or `None` if this backend cannot represent it.
+    fn modulus(m: &BigInt) -> Option<Self::Modulus>;
+
+    /// Reduce `value` into `[0, m)`.
+    fn from_bigint(value: &BigInt, modulus: &Self::Modulus) -> Self;
+
+    fn to_bigint(&self) -> BigInt;
+
+    /// Whether the value lies in `[0, m)`.
+    fn is_reduced(&self, modulus: &Self::Modulus) -> bool;
+
+    fn add_mod(&self, other: &Self, modulus: &Self::Modulus) -> Self;
+
+    fn sub_mod(&self, other: &Self, modulus: &Self::Modulus) -> Self;
+
+    fn mul_mod(&self, other: &Self, modulus: &Self::Modulus) -> Self;
+
+    /// A uniformly random value in `[0, m)`.
+    ///
+    /// All backends draw randomness the same way — little-endian 64-bit words
+    /// truncated to the bit length of `m - 1`, rejecting values `>= m` — so
+    /// they produce the same values from the same generator.
+    fn random<R: RngCore + ?Sized>(rng: &mut R, modulus: &Self::Modulus) -> Self;
+}
+
+impl Coefficient for BigInt {
+    type Modulus = BigInt;
+
+    fn modulus(m: &BigInt) -> Option<BigInt> {
+        (m.sign() == Sign::Plus).then(|| m.clone())
+    }
+
+    fn from_bigint(value: &BigInt, modulus: &BigInt) -> Self {
+        if value.sign() != Sign::Minus && value < modulus {
+            return value.clone();
+        }
+        let remainder = value % modulus;
+        if remainder.sign() == Sign::Minus {
+            remainder + modulus
+        } else {
+            remainder
+        }
+    }
+
+    fn to_bigint(&self) -> BigInt {
+        self.clone()
+    }
+
+    fn is_reduced(&self, modulus: &BigInt) -> bool {
+        self.sign() != Sign::Minus && self < modulus
+    }
+
+    fn add_mod(&self, other: &Self, modulus: &BigInt) -> Self {
+        Self::from_bigint(&(self + other), modulus)
+    }
+
+    fn sub_mod(&self, other: &Self, modulus: &BigInt) -> Self {
+        Self::from_bigint(&(self - other), modulus)
+    }
+
+    fn mul_mod(&self, other: &Self, modulus: &BigInt) -> Self {
+        Self::from_bigint(&(self * other), modulus)
+    }
+
+    fn random<R: RngCore + ?Sized>(rng: &mut R, modulus: &BigInt) -> Self {
+        let bits = (modulus - 1u32).bits();
+        let words = bits.div_ceil(64).max(1);
+        loop {
+            let mut value = BigInt::zero();
+            for i in 0..words {
+                value += BigInt::from(rng.next_u64()) << (64 * i);
+            }
+            value &= (BigInt::one() << bits) - 1u32;
+            if &value < modulus {
+                return value;
+            }
+        }
+    }
+}
+
+/// Fixed-width backend for `m = 2^k`, whose reduction data is the mask `2^k - 1`.
+macro_rules! impl_wrapping_coefficient {
+    ($word:ty) => {
+        impl Coefficient for $word {
+            type Modulus = $word;
+
+            fn modulus(m: &BigInt) -> Option<$word> {
+                let k = m.trailing_zeros()?;
+                if m.sign() != Sign::Plus || m.bits() != k + 1 || k == 0 || k > <$word>::BITS as u64
+                {
+                    return None;
+                }
+                Some(<$word>::MAX >> (<$word>::BITS as u64 - k))
+            }
+
+            fn from_bigint(value: &BigInt, modulus: &$word) -> Self {
+                let wrapped = value & BigInt::from(*modulus);
+                wrapped.to_u64().unwrap() as $word
+            }
+
+            fn to_bigint(&self) -> BigInt {
+                BigInt::from(*self)
+            }
+
+            fn is_reduced(&self, modulus: &$word) -> bool {
+                self & !modulus == 0
+            }
+
+            fn add_mod(&self, other: &Self, modulus: &$word) -> Self {
+                self.wrapping_add(*other) & modulus
+            }
+
+            fn sub_mod(&self, other: &Self, modulus: &$word) -> Self {
+                self.wrapping_sub(*other) & modulus
+            }
+
+            fn mul_mod(&self, other: &Self, modulus: &$word) -> Self {
+                self.wrapping_mul(*other) & modulus
+            }
+
+            fn random<R: RngCore + ?Sized>(rng: &mut R, modulus: &$word) -> Self {
+                (rng.next_u64() as $word) & modulus
+            }
+        }
+    };
+}
+
+impl_wrapping_coefficient!(u64);
+impl_wrapping_coefficient!(u32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_wrapping_modulus_detection() {
+        assert_eq!(u64::modulus(&(BigInt::one() << 64)), Some(u64::MAX));
+        assert_eq!(u64::modulus(&(BigInt::one() << 10)), Some(1023));
+        assert_eq!(u32::modulus(&(BigInt::one() << 64)), None);
+        assert_eq!(u64::modulus(&BigInt::from(7)), None);
+        assert_eq!(u64::modulus(&BigInt::from(12)), None);
+    }
+
+    #[test]
+    fn test_backends_agree() {
+        let m = BigInt::one() << 32;
+        let (big, wide, narrow) = (
+            BigInt::modulus(&m).unwrap(),
+            u64::modulus(&m).unwrap(),
+            u32::modulus(&m).unwrap(),
+        );
+        let a = BigInt::from(4_000_000_000u64);
+        let b = BigInt::from(3_000_000_000u64);
+
+        let expected = BigInt::mul_mod(&a, &b, &big);
+        let a64 = u64::from_bigint(&a, &wide);
+        let b64 = u64::from_bigint(&b, &wide);
+        assert_eq!(a64.mul_mod(&b64, &wide).to_bigint(), expected);
+        let a32 = u32::from_bigint(&a, &narrow);
+        let b32 = u32::from_bigint(&b, &narrow);
+        assert_eq!(
+            a32.sub_mod(&a32.add_mod(&b32, &narrow), &narrow)
+                .to_bigint(),
+            BigInt::from_bigint(&-b, &big)
+        );
+
+        let mut rng_big = StdRng::seed_from_u64(7);
+        let mut rng_wide = StdRng::seed_from_u64(7);
+        for _ in 0..10 {
+            assert_eq!(
+                BigInt::random(&mut rng_big, &big),
+                u64::random(&mut rng_wide, &wide).to_bigint()
+            );
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use num_bigint::BigInt;
+use std::fmt;
+
+/// Errors returned by ring construction, sharing and reconstruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The element has no multiplicative inverse in the ring.
+    NonUnit,
+    /// The modulus or defining polynomial does not describe a Galois ring.
+    InvalidRingParameters(String),
+    /// An element or share is not a reduced element of the ring it is used
+    /// with: a coefficient is out of range or the degree is too high.
+    MismatchedRing,
+    /// The threshold is zero or exceeds the number of parties.
+    InvalidThreshold { threshold: usize, parties: usize },
+    /// A sharing polynomial with more coefficients than the threshold allows.
+    TooManyCoefficients {
+        threshold: usize,
+        coefficients: usize,
+    },
+    /// More parties than non-zero points in the exceptional set.
+    TooManyParties { requested: usize, available: BigInt },
+    /// Fewer shares than reconstruction needs.
+    InsufficientShares { required: usize, provided: usize },
+    /// More shares or values than the operation takes, or counts that
+    /// should agree but differ.
+    WrongShareCount { expected: usize, provided: usize },
+    /// A packed sharing with no secrets.
+    NoSecrets,
+    /// Two shares or evaluation points coincide.
+    DuplicatePoint,
+    /// A share sits at a point reserved for a packed secret.
+    ReservedPoint,
+    /// Shares combined locally have different evaluation points.
+    MismatchedPoint,
+    /// Preprocessing has fewer unused triples than requested.
+    NotEnoughTriples { required: usize, available: usize },
+    /// A simulated party finished before sending or receiving a message.
+    PartyDisconnected(usize),
+    /// Opened values are inconsistent with their MACs.
+    MacCheckFailed,
+    /// Shares from different refresh epochs.
+    MismatchedEpoch,
+    /// Evaluation points whose pairwise differences are not all units.
+    NotExceptional,
+    /// The shares contain more errors than the decoder can correct.
+    TooManyErrors,
+    /// Encoded data is truncated, has trailing bytes or is otherwise malformed.
+    InvalidEncoding(String),
+    /// Encoded data uses a format version this crate cannot read.
+    UnsupportedVersion(u8),
+    /// Factoring a number took more work than allowed.
+    FactoringLimitExceeded,
+    /// The exceptional set is too large to enumerate.
+    ExceptionalSetTooLarge,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Check that exactly `expected` items were provided: too few is
+/// [`Error::InsufficientShares`], too many [`Error::WrongShareCount`].
+pub(crate) fn check_count(expected: usize, provided: usize) -> Result<()> {
+    if provided < expected {
+        return Err(Error::InsufficientShares {
+            required: expected,
+            provided,
+        });
+    }
+    if provided > expected {
+        return Err(Error::WrongShareCount { expected, provided });
+    }
+    Ok(())
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NonUnit => write!(f, "element is not a unit in the ring"),
+            Error::InvalidRingParameters(reason) => {
+                write!(f, "invalid ring parameters: {}", reason)
+            }
+            Error::MismatchedRing => write!(f, "element does not belong to this ring"),
+            Error::InvalidThreshold { threshold, parties } => write!(
+                f,
+                "threshold {} is invalid for {} parties",
+                threshold, parties
+            ),
+            Error::TooManyCoefficients {
+                threshold,
+                coefficients,
+            } => write!(
+                f,
+                "{} coefficients exceed the threshold {}",
+                coefficients, threshold
+            ),
+            Error::TooManyParties {
+                requested,
+                available,
+            } => write!(
+                f,
+                "{} parties requested but the exceptional set only has {} non-zero points",
+                requested, available
+            ),
+            Error::InsufficientShares { required, provided } => write!(
+                f,
+                "{} shares are required but only {} were provided",
+                required, provided
+            ),
+            Error::WrongShareCount { expected, provided } => write!(
+                f,
+                "expected {} shares or values but {} were provided",
+                expected, provided
+            ),
+            Error::NoSecrets => write!(f, "at least one secret is required"),
+            Error::DuplicatePoint => write!(f, "duplicate evaluation point"),
+            Error::ReservedPoint => write!(f, "share is at a point reserved for a secret"),
+            Error::MismatchedPoint => write!(f, "shares have different evaluation points"),
+            Error::NotEnoughTriples {
+                required,
+                available,
+            } => write!(
+                f,
+                "{} triples are required but only {} remain",
+                required, available
+            ),
+            Error::PartyDisconnected(party) => write!(f, "party {} disconnected", party),
+            Error::MacCheckFailed => write!(f, "MAC check failed"),
+            Error::MismatchedEpoch => write!(f, "shares belong to different refresh epochs"),
+            Error::NotExceptional => write!(
+                f,
+                "evaluation points together with zero do not form an exceptional set"
+            ),
+            Error::TooManyErrors => write!(f, "shares have more errors than can be corrected"),
+            Error::InvalidEncoding(reason) => write!(f, "invalid encoding: {}", reason),
+            Error::UnsupportedVersion(version) => {
+                write!(f, "unsupported format version {}", version)
+            }
+            Error::FactoringLimitExceeded => {
+                write!(f, "factoring exceeded the work limit")
+            }
+            Error::ExceptionalSetTooLarge => write!(f, "exceptional set is too large to enumerate"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
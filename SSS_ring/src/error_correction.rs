@@ -0,0 +1,266 @@
+use num_bigint::BigInt;
+use num_traits::Zero;
+
+use crate::coefficient::Coefficient;
+use crate::error::{Error, Result};
+use crate::galois_ring::GaloisRing;
+use crate::polynomial::Polynomial;
+use crate::sharing::Share;
+
+/// A secret recovered by [`GaloisRing::reconstruct_with_error_correction`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorrectedSecret<C: Coefficient = BigInt> {
+    pub secret: Polynomial<C>,
+    /// Positions in the input of the shares that disagree with the decoded
+    /// sharing polynomial, in increasing order.
+    pub corrupted_shares: Vec<usize>,
+}
+
+impl<C: Coefficient> GaloisRing<C> {
+    /// Recover the secret of a threshold-`t` sharing from `shares` when some
+    /// share values are wrong.
+    ///
+    /// With `n` shares, up to `e = (n - t) / 2` corrupted shares are corrected
+    /// and their positions in `shares` reported. The decoder works one
+    /// `p`-adic digit at a time: it runs Berlekamp–Welch in the residue field
+    /// on the current digit of the shares, treats the shares found to be wrong
+    /// as erasures, and moves on to the next digit of what the corrected
+    /// polynomial leaves unexplained.
+    ///
+    /// Fails with [`Error::TooManyErrors`] if the shares are not within
+    /// distance `e` of a sharing, with [`Error::InsufficientShares`] if there
+    /// are fewer than `t` shares, and on the same invalid shares as
+    /// [`GaloisRing::reconstruct_secret`].
+    pub fn reconstruct_with_error_correction(
+        &self,
+        shares: &[Share<C>],
+        t: usize,
+    ) -> Result<CorrectedSecret<C>> {
+        if t == 0 || shares.len() < t {
+            return Err(Error::InsufficientShares {
+                required: t.max(1),
+                provided: shares.len(),
+            });
+        }
+        self.validate_shares(shares)?;
+
+        let ring = self.to_bigint_ring();
+        let field = ring.residue_field();
+        let points: Vec<Polynomial> = shares.iter().map(|s| s.point().to_bigint()).collect();
+        let values: Vec<Polynomial> = shares.iter().map(|s| s.value().to_bigint()).collect();
+
+        // Values of the decoded polynomial modulo p^level at the active points
+        let mut decoded = vec![GaloisRing::zero(); shares.len()];
+        let mut active: Vec<usize> = (0..shares.len()).collect();
+        let mut corrupted = Vec::new();
+        let mut budget = (shares.len() - t) / 2;
+        let mut scale = BigInt::from(1);
+
+        for _ in 0..ring.exponent {
+            // The next p-adic digit of what is left to explain at each active point
+            let mut digits = Vec::with_capacity(active.len());
+            for &i in &active {
+                let residual = ring.sub_mod(&values[i], &decoded[i]);
+                if residual.coeffs.iter().any(|c| !(c % &scale).is_zero()) {
+                    return Err(Error::TooManyErrors);
+                }
+                let digit = Polynomial::new(residual.coeffs.iter().map(|c| c / &scale).collect());
+                digits.push(field.element_from_bigint(&digit));
+            }
+            let field_points: Vec<Polynomial> = active
+                .iter()
+                .map(|&i| field.element_from_bigint(&points[i]))
+                .collect();
+
+            // Lift the digit polynomial through t consistent points and add it
+            let nodes = berlekamp_welch(&field, &field_points, &digits, t, budget)?;
+            let lifted: Vec<Share> = nodes
+                .into_iter()
+                .map(|(j, value)| Share::from_parts(points[active[j]].clone(), value))
+                .collect();
+            let mut errors = Vec::new();
+            for (j, &i) in active.iter().enumerate() {
+                let digit = ring.interpolate_at(&lifted, &points[i])?;
+                if field.element_from_bigint(&digit) != digits[j] {
+                    errors.push(i);
+                }
+                let term = Polynomial::new(digit.coeffs.iter().map(|c| c * &scale).collect());
+                decoded[i] = ring.add_ring(&decoded[i], &ring.element_from_bigint(&term));
+            }
+
+            if errors.len() > budget {
+                return Err(Error::TooManyErrors);
+            }
+            budget -= errors.len();
+            active.retain(|i| !errors.contains(i));
+            corrupted.extend(errors);
+            scale *= &ring.prime;
+        }
+
+        let clean: Vec<Share<C>> = active[..t].iter().map(|&i| shares[i].clone()).collect();
+        let secret = self.interpolate_at(&clean, &GaloisRing::zero())?;
+        corrupted.sort_unstable();
+        Ok(CorrectedSecret {
+            secret,
+            corrupted_shares: corrupted,
+        })
+    }
+}
+
+/// Berlekamp–Welch decoding over a field of the polynomial of degree below
+/// `t` through `(points[i], values[i])` with at most `max_errors` errors.
+///
+/// Solves `Q(x_i) = y_i E(x_i)` for a monic error locator `E` of degree
+/// `max_errors` and `Q` of degree below `t + max_errors`, and returns `t`
+/// pairs `(i, Q(x_i) / E(x_i))` at points where `E` does not vanish, which
+/// determine the decoded polynomial.
+fn berlekamp_welch(
+    field: &GaloisRing,
+    points: &[Polynomial],
+    values: &[Polynomial],
+    t: usize,
+    max_errors: usize,
+) -> Result<Vec<(usize, Polynomial)>> {
+    // Unknowns: E_0, ..., E_{e-1}, then Q_0, ..., Q_{t+e-1}
+    let unknowns = t + 2 * max_errors;
+    let rows: Vec<Vec<Polynomial>> = points
+        .iter()
+        .zip(values)
+        .map(|(x, y)| {
+            let powers: Vec<Polynomial> = (0..t + max_errors)
+                .map(|j| field.power_in_ring(x, j))
+                .collect();
+            let mut row: Vec<Polynomial> = powers[..max_errors]
+                .iter()
+                .map(|power| field.sub_mod(&GaloisRing::zero(), &field.mul_ring(y, power)))
+                .collect();
+            row.extend(powers.iter().cloned());
+            row.push(field.mul_ring(y, &field.power_in_ring(x, max_errors)));
+            row
+        })
+        .collect();
+    let solution = solve_linear_system(field, rows, unknowns)?;
+
+    let mut locator = solution[..max_errors].to_vec();
+    locator.push(GaloisRing::one());
+    let numerator = &solution[max_errors..];
+
+    let mut nodes = Vec::with_capacity(t);
+    for (i, x) in points.iter().enumerate() {
+        let e = field.evaluate_polynomial(x, &locator);
+        if !e.is_zero() {
+            let q = field.evaluate_polynomial(x, numerator);
+            nodes.push((
+                i,
+                field.mul_ring(&q, &field.find_inverse_in_galois_ring(&e)?),
+            ));
+            if nodes.len() == t {
+                return Ok(nodes);
+            }
+        }
+    }
+    Err(Error::TooManyErrors)
+}
+
+/// Solve the augmented system `rows` over a field by Gauss–Jordan elimination,
+/// setting free unknowns to zero. Fails with [`Error::TooManyErrors`] if the
+/// system is inconsistent.
+fn solve_linear_system(
+    field: &GaloisRing,
+    mut rows: Vec<Vec<Polynomial>>,
+    unknowns: usize,
+) -> Result<Vec<Polynomial>> {
+    let mut pivots = Vec::new();
+    let mut rank = 0;
+    for col in 0..unknowns {
+        let Some(pivot) = (rank..rows.len()).find(|&r| !rows[r][col].is_zero()) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        let inverse = field.find_inverse_in_galois_ring(&rows[rank][col])?;
+        let pivot_row: Vec<Polynomial> = rows[rank]
+            .iter()
+            .map(|a| field.mul_ring(a, &inverse))
+            .collect();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != rank && !row[col].is_zero() {
+                let factor = row[col].clone();
+                for (a, b) in row.iter_mut().zip(&pivot_row) {
+                    *a = field.sub_mod(a, &field.mul_ring(&factor, b));
+                }
+            }
+        }
+        rows[rank] = pivot_row;
+        pivots.push(col);
+        rank += 1;
+    }
+    if rows[rank..].iter().any(|row| !row[unknowns].is_zero()) {
+        return Err(Error::TooManyErrors);
+    }
+
+    let mut solution = vec![GaloisRing::zero(); unknowns];
+    for (row, col) in rows.iter().zip(pivots) {
+        solution[col] = row[unknowns].clone();
+    }
+    Ok(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::irreducible::irreducible_polynomial;
+
+    #[test]
+    fn test_corrects_errors_in_every_digit() {
+        let ring =
+            GaloisRing::new(BigInt::from(1) << 16, irreducible_polynomial(3).unwrap()).unwrap();
+        let wide = GaloisRing::<u64>::from_bigint_ring(&ring).unwrap();
+        let secret = wide.random_ring_element();
+        let mut shares = wide.shamir_secret_sharing(secret.clone(), 7, 3).unwrap();
+
+        // One error visible modulo 2 and one only visible modulo 2^9
+        shares[1] = shares[1].add_constant(&GaloisRing::one(), &wide).unwrap();
+        shares[4] = shares[4]
+            .add_constant(&Polynomial::new(vec![0, 1 << 8]), &wide)
+            .unwrap();
+
+        let corrected = wide.reconstruct_with_error_correction(&shares, 3).unwrap();
+        assert_eq!(corrected.secret, secret);
+        assert_eq!(corrected.corrupted_shares, vec![1, 4]);
+    }
+
+    #[test]
+    fn test_error_correction_over_odd_characteristic() {
+        let ring = GaloisRing::new(BigInt::from(343), irreducible_polynomial(3).unwrap()).unwrap();
+        let secret = ring.random_ring_element();
+        let mut shares = ring.shamir_secret_sharing(secret.clone(), 10, 4).unwrap();
+
+        let corrected = ring.reconstruct_with_error_correction(&shares, 4).unwrap();
+        assert_eq!(corrected.secret, secret);
+        assert!(corrected.corrupted_shares.is_empty());
+
+        // Shares 0, 5 and 9 are off by 49 = 7^2, visible only in the last digit
+        let offset = Polynomial::constant(BigInt::from(49));
+        for i in [0, 5, 9] {
+            shares[i] = shares[i].add_constant(&offset, &ring).unwrap();
+        }
+        let corrected = ring.reconstruct_with_error_correction(&shares, 4).unwrap();
+        assert_eq!(corrected.secret, secret);
+        assert_eq!(corrected.corrupted_shares, vec![0, 5, 9]);
+
+        // A fourth error is beyond (10 - 4) / 2 = 3: once the error in the
+        // lowest digit is corrected, only two remain for the three in 49
+        shares[2] = shares[2].add_constant(&GaloisRing::one(), &ring).unwrap();
+        assert_eq!(
+            ring.reconstruct_with_error_correction(&shares, 4),
+            Err(Error::TooManyErrors)
+        );
+        assert_eq!(
+            ring.reconstruct_with_error_correction(&shares[..3], 4),
+            Err(Error::InsufficientShares {
+                required: 4,
+                provided: 3
+            })
+        );
+    }
+}
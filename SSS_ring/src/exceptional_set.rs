@@ -1,60 +1,128 @@
-extern crate ff;
-use ff::{Field, PrimeField, FieldExt, Polynomial};
 use num_bigint::BigInt;
-use std::collections::HashSet;
+use num_traits::ToPrimitive;
 
-#[derive(Clone, Debug)]
-struct GF2dField {
-    poly: Polynomial,
-    modulus: Polynomial,
-}
+use crate::coefficient::Coefficient;
+use crate::error::{Error, Result};
+use crate::galois_ring::GaloisRing;
+use crate::polynomial::Polynomial;
 
-impl GF2dField {
-    fn new(modulus: Polynomial) -> Self {
-        GF2dField {
-            poly: Polynomial::zero(),
-            modulus,
-        }
+impl<C: Coefficient> GaloisRing<C> {
+    /// Size `p^d` of a maximal exceptional set, the largest possible set of
+    /// ring elements whose pairwise differences are all units.
+    pub fn exceptional_set_size(&self) -> BigInt {
+        self.prime.pow(self.degree() as u32)
     }
 
-    fn add(&self, other: &Self) -> Self {
-        GF2dField {
-            poly: &self.poly + &other.poly,
-            modulus: self.modulus.clone(),
+    /// The `index`-th digit vector: the element whose coefficients are the
+    /// base-`p` digits of `index`.
+    ///
+    /// Distinct digit vectors differ modulo `p`, so their difference is a unit.
+    pub fn exceptional_point(&self, index: &BigInt) -> Polynomial<C> {
+        let mut coeffs = Vec::with_capacity(self.degree());
+        let mut value = index.clone();
+
+        // Convert the index to a polynomial by interpreting its base-p digits as coefficients
+        for _ in 0..self.degree() {
+            coeffs.push(&value % &self.prime);
+            value /= &self.prime;
         }
+
+        self.element_from_bigint(&Polynomial::new(coeffs))
     }
 
-    fn mul(&self, other: &Self) -> Self {
-        let mut result = &self.poly * &other.poly;
-        result = result % &self.modulus;
-        GF2dField {
-            poly: result,
-            modulus: self.modulus.clone(),
+    /// The first `count` non-zero digit vectors, for use as evaluation points.
+    ///
+    /// Fails with [`Error::TooManyParties`] if `count` exceeds `p^d - 1`.
+    pub fn exceptional_points(&self, count: usize) -> Result<Vec<Polynomial<C>>> {
+        let available = self.exceptional_set_size() - 1;
+        if BigInt::from(count) > available {
+            return Err(Error::TooManyParties {
+                requested: count,
+                available,
+            });
         }
+        Ok((1..=count)
+            .map(|i| self.exceptional_point(&BigInt::from(i)))
+            .collect())
+    }
+
+    /// A maximal exceptional set: all `p^d` digit vectors, including zero.
+    ///
+    /// Fails with [`Error::ExceptionalSetTooLarge`] if `p^d` does not fit in a `usize`.
+    pub fn maximal_exceptional_set(&self) -> Result<Vec<Polynomial<C>>> {
+        let size = self
+            .exceptional_set_size()
+            .to_usize()
+            .ok_or(Error::ExceptionalSetTooLarge)?;
+        Ok((0..size)
+            .map(|i| self.exceptional_point(&BigInt::from(i)))
+            .collect())
+    }
+
+    /// The non-zero points of the maximal exceptional set.
+    ///
+    /// Zero is reserved for the secret, so these `p^d - 1` points are the
+    /// evaluation points available to parties.
+    pub fn generate_exceptional_set(&self) -> Result<Vec<Polynomial<C>>> {
+        let mut exceptional_set = self.maximal_exceptional_set()?;
+        exceptional_set.remove(0);
+        Ok(exceptional_set)
+    }
+
+    /// Whether every pairwise difference of `points` is a unit.
+    pub fn is_exceptional_set(&self, points: &[Polynomial<C>]) -> bool {
+        points.iter().enumerate().all(|(i, xi)| {
+            points[i + 1..]
+                .iter()
+                .all(|xj| self.is_unit(&self.sub_mod(xj, xi)))
+        })
     }
-}
 
-fn generate_exceptional_set(d: usize, modulus: Polynomial) -> HashSet<GF2dField> {
-    let mut exceptional_set = HashSet::new();
-    let max_value = 1 << d;
-    
-    for i in 1..max_value {
-        let poly = Polynomial::from_coeffs((0..d).map(|j| ((i >> j) & 1).into()).collect());
-        let element = GF2dField::new(poly.clone());
-        exceptional_set.insert(element);
+    /// Whether `points` can be used as party evaluation points, i.e. together
+    /// with the secret point zero they form an exceptional set.
+    pub fn is_valid_evaluation_set(&self, points: &[Polynomial<C>]) -> bool {
+        points.iter().all(|x| self.is_unit(x)) && self.is_exceptional_set(points)
     }
-    exceptional_set
 }
 
-fn main() {
-    // Define the irreducible polynomial for the field extension GF(2^d)
-    let irreducible_poly = Polynomial::from_coeffs(vec![1, 0, 1, 1]); // Example for x^3 + x + 1
-    
-    let d = 3; // Degree of the extension
-    let exceptional_set = generate_exceptional_set(d, irreducible_poly);
-    
-    // Print the exceptional set
-    for elem in exceptional_set {
-        println!("{:?}", elem);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_traits::Zero;
+
+    /// `GR(27, 2)` defined by `x^2 + 1`, which is irreducible modulo 3.
+    fn ring_mod_27() -> GaloisRing {
+        let irreducible = Polynomial::new(vec![BigInt::from(1), BigInt::zero(), BigInt::from(1)]);
+        GaloisRing::new(BigInt::from(27), irreducible).unwrap()
+    }
+
+    #[test]
+    fn test_maximal_exceptional_set_for_odd_prime() {
+        let ring = ring_mod_27();
+
+        let points = ring.maximal_exceptional_set().unwrap();
+
+        assert_eq!(points.len(), 9);
+        assert!(ring.is_exceptional_set(&points));
+        assert_eq!(ring.generate_exceptional_set().unwrap().len(), 8);
+        assert!(matches!(
+            ring.exceptional_points(9),
+            Err(Error::TooManyParties { requested: 9, .. })
+        ));
+    }
+
+    #[test]
+    fn test_rejects_points_with_non_unit_difference() {
+        let ring = ring_mod_27();
+
+        // 1 and 4 differ by 3, which is divisible by p
+        let points = vec![
+            Polynomial::constant(BigInt::from(1)),
+            Polynomial::constant(BigInt::from(4)),
+        ];
+
+        assert!(!ring.is_exceptional_set(&points));
+        assert!(ring.is_valid_evaluation_set(&points[..1]));
+        assert!(!ring.is_valid_evaluation_set(&[Polynomial::constant(BigInt::zero())]));
     }
 }
@@ -0,0 +1,600 @@
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Zero};
+
+use crate::arithmetic::{karatsuba, Reducer};
+use crate::coefficient::Coefficient;
+use crate::error::{Error, Result};
+use crate::irreducible::is_irreducible_mod_p;
+use crate::number_theory::{is_probable_prime, prime_power_decomposition};
+use crate::polynomial::Polynomial;
+
+/// The Galois ring `GR(p^k, d) = (Z/p^kZ)[x] / (h(x))` for a monic polynomial
+/// `h` of degree `d` that is irreducible modulo `p`.
+///
+/// For `k = 1` this is the finite field `GF(p^d)`. Coefficients are stored
+/// with the backend `C`; see [`Coefficient`].
+#[derive(Debug, Clone)]
+pub struct GaloisRing<C: Coefficient = BigInt> {
+    pub(crate) modulus: BigInt,
+    pub(crate) prime: BigInt,
+    pub(crate) exponent: u32,
+    pub(crate) irreducible: Polynomial<C>,
+    pub(crate) coefficient_modulus: C::Modulus,
+    pub(crate) reducer: Reducer<C>,
+}
+
+/// `GR(2^k, d)` with `u64` coefficients, for `k <= 64`.
+pub type GaloisRing64 = GaloisRing<u64>;
+
+/// `GR(2^k, d)` with `u32` coefficients, for `k <= 32`.
+pub type GaloisRing32 = GaloisRing<u32>;
+
+impl GaloisRing {
+    /// Build the ring with coefficient modulus `modulus = p^k`.
+    ///
+    /// Fails if `modulus` is not a prime power or `irreducible` is not a monic
+    /// polynomial that is irreducible modulo `p`.
+    pub fn new(modulus: BigInt, irreducible: Polynomial) -> Result<Self> {
+        let (prime, exponent) = prime_power_decomposition(&modulus).ok_or_else(|| {
+            Error::InvalidRingParameters(format!("modulus {} is not a prime power", modulus))
+        })?;
+        GaloisRing::from_prime_power(prime, exponent, irreducible)
+    }
+
+    /// Build `GR(p^k, deg(irreducible))`.
+    ///
+    /// Fails if `prime` is not prime, `exponent` is zero, or `irreducible` is
+    /// not a monic polynomial of positive degree that is irreducible modulo `p`.
+    pub fn from_prime_power(prime: BigInt, exponent: u32, irreducible: Polynomial) -> Result<Self> {
+        if !is_probable_prime(&prime) {
+            return Err(Error::InvalidRingParameters(format!(
+                "{} is not prime",
+                prime
+            )));
+        }
+        if exponent == 0 {
+            return Err(Error::InvalidRingParameters(
+                "the exponent must be at least 1".into(),
+            ));
+        }
+        let irreducible = irreducible.trimmed_poly();
+        if !irreducible.is_monic() || irreducible.degree() == 0 {
+            return Err(Error::InvalidRingParameters(
+                "the irreducible polynomial must be monic of positive degree".into(),
+            ));
+        }
+        if !is_irreducible_mod_p(&irreducible, &prime) {
+            return Err(Error::InvalidRingParameters(format!(
+                "{:?} is not irreducible modulo {}",
+                irreducible.coeffs, prime
+            )));
+        }
+        Ok(GaloisRing::new_unchecked(prime, exponent, irreducible))
+    }
+
+    /// Build the ring without validating its parameters. `irreducible` must be
+    /// monic and trimmed.
+    pub(crate) fn new_unchecked(prime: BigInt, exponent: u32, irreducible: Polynomial) -> Self {
+        let modulus = prime.pow(exponent);
+        GaloisRing {
+            reducer: Reducer::new(&irreducible.coeffs, &modulus),
+            coefficient_modulus: modulus.clone(),
+            modulus,
+            prime,
+            exponent,
+            irreducible,
+        }
+    }
+}
+
+impl<C: Coefficient> GaloisRing<C> {
+    /// The same ring with coefficients stored in the backend `C`.
+    ///
+    /// Fails if `C` cannot represent the modulus of `ring`, e.g. a `u64`
+    /// backend for a modulus other than `2^k` with `k <= 64`.
+    pub fn from_bigint_ring(ring: &GaloisRing) -> Result<Self> {
+        let coefficient_modulus = C::modulus(&ring.modulus).ok_or_else(|| {
+            Error::InvalidRingParameters(format!(
+                "modulus {} is not supported by this coefficient backend",
+                ring.modulus
+            ))
+        })?;
+        let irreducible = Polynomial::from_bigint(&ring.irreducible, &coefficient_modulus);
+        Ok(GaloisRing {
+            modulus: ring.modulus.clone(),
+            prime: ring.prime.clone(),
+            exponent: ring.exponent,
+            reducer: Reducer::new(&irreducible.coeffs, &coefficient_modulus),
+            irreducible,
+            coefficient_modulus,
+        })
+    }
+
+    /// The same ring with [`BigInt`] coefficients.
+    pub fn to_bigint_ring(&self) -> GaloisRing {
+        GaloisRing::new_unchecked(
+            self.prime.clone(),
+            self.exponent,
+            self.irreducible.to_bigint(),
+        )
+    }
+
+    /// The coefficient modulus `m = p^k`.
+    pub fn modulus(&self) -> &BigInt {
+        &self.modulus
+    }
+
+    /// The backend's reduction data for `m`.
+    pub fn coefficient_modulus(&self) -> &C::Modulus {
+        &self.coefficient_modulus
+    }
+
+    /// The residue characteristic `p`.
+    pub fn prime(&self) -> &BigInt {
+        &self.prime
+    }
+
+    /// The exponent `k` of the coefficient modulus `p^k`.
+    pub fn exponent(&self) -> u32 {
+        self.exponent
+    }
+
+    /// The defining polynomial `h`.
+    pub fn irreducible(&self) -> &Polynomial<C> {
+        &self.irreducible
+    }
+
+    /// The extension degree `d = deg(h)`.
+    pub fn degree(&self) -> usize {
+        self.irreducible.degree()
+    }
+
+    /// The residue field `GF(p^d) = F_p[x] / (h(x) mod p)`.
+    pub fn residue_field(&self) -> GaloisRing {
+        let irreducible = Polynomial::from_bigint(&self.irreducible.to_bigint(), &self.prime);
+        GaloisRing::new_unchecked(self.prime.clone(), 1, irreducible)
+    }
+
+    /// The ring element with the given [`BigInt`] coefficients, each reduced modulo `m`.
+    pub fn element_from_bigint(&self, poly: &Polynomial) -> Polynomial<C> {
+        Polynomial::from_bigint(poly, &self.coefficient_modulus)
+    }
+
+    /// Check that `elem` is a reduced element of this ring: coefficients in
+    /// `[0, p^k)` and degree below `d`.
+    pub fn validate_element(&self, elem: &Polynomial<C>) -> Result<()> {
+        let in_range = elem
+            .coeffs
+            .iter()
+            .all(|c| c.is_reduced(&self.coefficient_modulus));
+        let trimmed = elem.trimmed_poly();
+        if !in_range || (!trimmed.coeffs.is_empty() && trimmed.degree() >= self.degree()) {
+            return Err(Error::MismatchedRing);
+        }
+        Ok(())
+    }
+
+    /// The zero element.
+    pub fn zero() -> Polynomial<C> {
+        Polynomial::zero()
+    }
+
+    /// The multiplicative identity.
+    pub fn one() -> Polynomial<C> {
+        Polynomial::one()
+    }
+
+    /// Coefficient-wise addition modulo `m`, without reduction modulo `h`.
+    pub fn add(&self, poly1: &Polynomial<C>, poly2: &Polynomial<C>) -> Polynomial<C> {
+        let mut result = vec![C::zero(); poly1.coeffs.len().max(poly2.coeffs.len())];
+        let zero = C::zero(); // Avoid temporary value issues
+
+        for (i, r) in result.iter_mut().enumerate() {
+            let a = poly1.coeffs.get(i).unwrap_or(&zero);
+            let b = poly2.coeffs.get(i).unwrap_or(&zero);
+            *r = a.add_mod(b, &self.coefficient_modulus);
+        }
+
+        Polynomial::new(result)
+    }
+
+    /// Addition in the ring.
+    pub fn add_ring(&self, poly1: &Polynomial<C>, poly2: &Polynomial<C>) -> Polynomial<C> {
+        self.reduce(&self.add(poly1, poly2))
+    }
+
+    /// Coefficient-wise subtraction modulo `m`, with non-negative results.
+    pub fn sub_mod(&self, poly1: &Polynomial<C>, poly2: &Polynomial<C>) -> Polynomial<C> {
+        let mut result = vec![C::zero(); poly1.coeffs.len().max(poly2.coeffs.len())];
+        let zero = C::zero(); // Avoid temporary value issues
+
+        for (i, r) in result.iter_mut().enumerate() {
+            let a = poly1.coeffs.get(i).unwrap_or(&zero);
+            let b = poly2.coeffs.get(i).unwrap_or(&zero);
+            *r = a.sub_mod(b, &self.coefficient_modulus);
+        }
+
+        Polynomial::new(result)
+    }
+
+    /// Polynomial product with coefficients reduced modulo `m`, without
+    /// reduction modulo `h`.
+    ///
+    /// Long operands, as in rings of large degree, are multiplied with
+    /// Karatsuba's method.
+    pub fn mul(&self, poly1: &Polynomial<C>, poly2: &Polynomial<C>) -> Polynomial<C> {
+        let product = karatsuba(&poly1.coeffs, &poly2.coeffs, &self.coefficient_modulus);
+        if product.is_empty() {
+            return GaloisRing::zero();
+        }
+        Polynomial::new(product)
+    }
+
+    /// Multiplication in the ring.
+    pub fn mul_ring(&self, poly1: &Polynomial<C>, poly2: &Polynomial<C>) -> Polynomial<C> {
+        self.reduce(&self.mul(poly1, poly2))
+    }
+
+    /// `poly^exponent` in the ring; see [`GaloisRing::pow`] for big exponents.
+    pub fn power_in_ring(&self, poly: &Polynomial<C>, exponent: usize) -> Polynomial<C> {
+        self.pow(poly, &BigUint::from(exponent))
+    }
+
+    /// Reduce `poly` modulo the defining polynomial `h`.
+    ///
+    /// Uses the ring's precomputed reducer: sparse folding when `h` has few
+    /// terms, and a table of `x^j mod h` otherwise. Only polynomials longer
+    /// than a product of two reduced elements fall back to long division.
+    pub fn reduce(&self, poly: &Polynomial<C>) -> Polynomial<C> {
+        let mut remainder = poly.trimmed_poly();
+        if !self
+            .reducer
+            .reduce(&mut remainder.coeffs, &self.coefficient_modulus)
+        {
+            return self.divide_by_monic(poly, &self.irreducible).1;
+        }
+        remainder.trim();
+        remainder
+    }
+
+    /// Divide `dividend` by `divisor`, returning `(quotient, remainder)`.
+    ///
+    /// Fails with [`Error::NonUnit`] unless the leading coefficient of the
+    /// divisor is a unit modulo `m`.
+    pub fn polynomial_long_division(
+        &self,
+        dividend: &Polynomial<C>,
+        divisor: &Polynomial<C>,
+    ) -> Result<(Polynomial<C>, Polynomial<C>)> {
+        let divisor_trimmed = divisor.trimmed_poly(); // in case the leading coefficient/s is 0
+        let leading_coeff_divisor = divisor_trimmed.coeffs.last().ok_or(Error::NonUnit)?;
+        if leading_coeff_divisor.is_one() {
+            return Ok(self.divide_by_monic(dividend, &divisor_trimmed));
+        }
+
+        // Divide by the monic associate of the divisor and rescale the quotient
+        let leading_inverse = self.mod_inverse(leading_coeff_divisor.to_bigint())?;
+        let leading_inverse =
+            Polynomial::constant(C::from_bigint(&leading_inverse, &self.coefficient_modulus));
+        let monic_divisor = self.mul(&divisor_trimmed, &leading_inverse);
+        let (quotient, remainder) = self.divide_by_monic(dividend, &monic_divisor);
+        Ok((self.mul(&quotient, &leading_inverse), remainder))
+    }
+
+    /// Long division by a trimmed monic `divisor`, which needs no inversion.
+    ///
+    /// Works in place on the coefficients of the remainder, eliminating the
+    /// leading term one degree at a time.
+    fn divide_by_monic(
+        &self,
+        dividend: &Polynomial<C>,
+        divisor: &Polynomial<C>,
+    ) -> (Polynomial<C>, Polynomial<C>) {
+        let m = &self.coefficient_modulus;
+        let mut remainder = dividend.trimmed_poly();
+        let divisor_degree = divisor.degree();
+
+        if remainder.coeffs.len() <= divisor_degree {
+            return (GaloisRing::zero(), remainder);
+        }
+
+        let mut quotient = vec![C::zero(); remainder.coeffs.len() - divisor_degree];
+        for i in (divisor_degree..remainder.coeffs.len()).rev() {
+            // The quotient coefficient is the leading coefficient of the remainder
+            let quotient_coeff = remainder.coeffs[i].clone();
+            if quotient_coeff.is_zero() {
+                continue;
+            }
+
+            // Subtract quotient_coeff * x^(i - deg) * divisor from the remainder
+            let shift = i - divisor_degree;
+            for (j, h) in divisor.coeffs.iter().enumerate() {
+                let term = quotient_coeff.mul_mod(h, m);
+                remainder.coeffs[shift + j] = remainder.coeffs[shift + j].sub_mod(&term, m);
+            }
+            quotient[shift] = quotient_coeff;
+        }
+
+        remainder.coeffs.truncate(divisor_degree);
+        remainder.trim();
+        (Polynomial::new(quotient), remainder)
+    }
+
+    /// Extended Euclidean algorithm on polynomials.
+    ///
+    /// Returns `(g, t, s)` with `s * a + t * b = g`. Fails with
+    /// [`Error::NonUnit`] if a remainder has a non-unit leading coefficient,
+    /// which cannot happen over a field.
+    pub fn extended_euclidean(
+        &self,
+        a: &Polynomial<C>,
+        b: &Polynomial<C>,
+    ) -> Result<(Polynomial<C>, Polynomial<C>, Polynomial<C>)> {
+        let mut s = GaloisRing::zero();
+        let mut old_s = GaloisRing::one();
+        let mut t = GaloisRing::one();
+        let mut old_t = GaloisRing::zero();
+        let mut r = b.trimmed_poly();
+        let mut old_r = a.clone();
+
+        while !r.coeffs.is_empty() {
+            let (quotient, remainder) = self.polynomial_long_division(&old_r, &r)?;
+
+            old_r = r;
+            r = remainder.trimmed_poly();
+
+            let new_s = self.sub_mod(&old_s, &self.mul(&quotient, &s));
+            old_s = std::mem::replace(&mut s, new_s);
+
+            let new_t = self.sub_mod(&old_t, &self.mul(&quotient, &t));
+            old_t = std::mem::replace(&mut t, new_t);
+        }
+
+        Ok((old_r, old_t, old_s))
+    }
+
+    /// Inverse of an integer modulo `m`, or [`Error::NonUnit`] if it does not exist.
+    pub fn mod_inverse(&self, divisor: BigInt) -> Result<BigInt> {
+        let mut t = BigInt::zero();
+        let mut new_t = BigInt::one();
+        let mut r = self.modulus.clone();
+        let mut new_r = ((divisor % &self.modulus) + &self.modulus) % &self.modulus;
+
+        while !new_r.is_zero() {
+            let quotient = &r / &new_r;
+
+            let temp_t = t;
+            t = new_t.clone();
+            new_t = temp_t - &quotient * &new_t;
+
+            let temp_r = r;
+            r = new_r.clone();
+            new_r = temp_r - quotient * new_r;
+        }
+
+        if !r.is_one() {
+            Err(Error::NonUnit)
+        } else {
+            Ok(((t % &self.modulus) + &self.modulus) % &self.modulus) // Ensure result is positive and within modulus
+        }
+    }
+
+    /// Whether `elem` is a unit, i.e. non-zero modulo the maximal ideal `(p)`.
+    pub fn is_unit(&self, elem: &Polynomial<C>) -> bool {
+        self.reduce(elem)
+            .coeffs
+            .iter()
+            .any(|c| !(c.to_bigint() % &self.prime).is_zero())
+    }
+
+    /// Invert every element of `elems` with a single ring inversion, using
+    /// Montgomery's trick of inverting the product and peeling off factors.
+    ///
+    /// Fails with [`Error::NonUnit`] if any element is not a unit.
+    pub fn batch_inverse(&self, elems: &[Polynomial<C>]) -> Result<Vec<Polynomial<C>>> {
+        let mut prefixes = Vec::with_capacity(elems.len());
+        let mut product = GaloisRing::one();
+        for elem in elems {
+            prefixes.push(product.clone());
+            product = self.mul_ring(&product, elem);
+        }
+
+        let mut inverse = self.find_inverse_in_galois_ring(&product)?;
+        let mut inverses = vec![GaloisRing::zero(); elems.len()];
+        for (i, elem) in elems.iter().enumerate().rev() {
+            inverses[i] = self.mul_ring(&inverse, &prefixes[i]);
+            inverse = self.mul_ring(&inverse, elem);
+        }
+        Ok(inverses)
+    }
+
+    /// Find the inverse of an element in the Galois ring.
+    ///
+    /// The inverse is computed in the residue field `GF(p^d)` with the extended
+    /// Euclidean algorithm and lifted to `GR(p^k, d)` by Newton iteration
+    /// `b <- b * (2 - a * b)`, which doubles the `p`-adic precision each step.
+    /// Fails with [`Error::NonUnit`] for non-units.
+    pub fn find_inverse_in_galois_ring(&self, elem: &Polynomial<C>) -> Result<Polynomial<C>> {
+        if !self.is_unit(elem) {
+            return Err(Error::NonUnit);
+        }
+
+        let field = self.residue_field();
+        let elem_mod_p = field.reduce(&field.element_from_bigint(&elem.to_bigint()));
+        let (g, u, _) = field.extended_euclidean(&field.irreducible, &elem_mod_p)?;
+
+        // The gcd is a non-zero constant since h is irreducible modulo p; divide u by it
+        let g_inv = field.mod_inverse(g.coeffs[0].clone())?;
+        let u = field.mul(&u, &Polynomial::constant(g_inv));
+        let mut inverse = self.element_from_bigint(&field.reduce(&u));
+
+        let two = Polynomial::constant(C::from_bigint(&BigInt::from(2), &self.coefficient_modulus));
+        let mut precision = 1;
+        while precision < self.exponent {
+            let correction = self.sub_mod(&two, &self.mul_ring(elem, &inverse));
+            inverse = self.mul_ring(&inverse, &correction);
+            precision *= 2;
+        }
+
+        Ok(inverse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::irreducible::irreducible_polynomial;
+    use crate::test_utils::ring_2_64;
+
+    #[test]
+    fn test_polynomial_long_division() {
+        let ring = GaloisRing::new(BigInt::from(7), irreducible_polynomial(3).unwrap()).unwrap();
+
+        // Define the dividend: x^4 + x + 1
+        let dividend = Polynomial::new(vec![
+            BigInt::from(1), // Coefficient for x^0
+            BigInt::from(1), // Coefficient for x^1
+            BigInt::from(0), // Coefficient for x^2
+            BigInt::from(0), // Coefficient for x^3
+            BigInt::from(1), // Coefficient for x^4
+        ]);
+
+        // Define the divisor: x^2 + 1
+        let divisor = Polynomial::new(vec![
+            BigInt::from(1), // Coefficient for x^0
+            BigInt::from(0), // Coefficient for x^1
+            BigInt::from(1), // Coefficient for x^2
+        ]);
+
+        let (quotient, remainder) = ring.polynomial_long_division(&dividend, &divisor).unwrap();
+
+        // Expected quotient: x^2 - 1
+        let expected_quotient = Polynomial::new(vec![
+            BigInt::from(6), // Coefficient for x^0
+            BigInt::from(0), // Coefficient for x^1
+            BigInt::from(1), // Coefficient for x^2
+        ]);
+
+        // Expected remainder: x + 2
+        let expected_remainder = Polynomial::new(vec![
+            BigInt::from(2), // Coefficient for x^0
+            BigInt::from(1), // Coefficient for x^1
+        ]);
+
+        assert_eq!(
+            quotient, expected_quotient,
+            "Quotient does not match expected value"
+        );
+        assert_eq!(
+            remainder, expected_remainder,
+            "Remainder does not match expected value"
+        );
+    }
+
+    #[test]
+    fn test_extended_euclidean() {
+        let ring = GaloisRing::new(BigInt::from(7), irreducible_polynomial(3).unwrap()).unwrap();
+
+        // x^4 + x^3 + x + 1 and x^2 + 1
+        let a = Polynomial::new(vec![
+            BigInt::from(1), // Coefficient for x^0
+            BigInt::from(1), // Coefficient for x^1
+            BigInt::from(0), // Coefficient for x^2
+            BigInt::from(1), // Coefficient for x^3
+            BigInt::from(1), // Coefficient for x^4
+        ]);
+        let b = Polynomial::new(vec![
+            BigInt::from(1), // Coefficient for x^0
+            BigInt::from(0), // Coefficient for x^1
+            BigInt::from(1), // Coefficient for x^2
+        ]);
+
+        let (gcd, t, s) = ring.extended_euclidean(&a, &b).unwrap();
+
+        // Verify that s*a + t*b = gcd
+        let left_side = ring
+            .add(&ring.mul(&s, &a), &ring.mul(&t, &b))
+            .trimmed_poly();
+        assert_eq!(left_side, gcd, "s*a + t*b should equal gcd");
+
+        assert!(
+            gcd.degree() <= b.degree(),
+            "GCD should have a degree less than or equal to the smaller polynomial"
+        );
+    }
+
+    #[test]
+    fn test_inverse_in_galois_ring() {
+        let ring = GaloisRing::new(BigInt::from(7), irreducible_polynomial(3).unwrap()).unwrap();
+        let elem = Polynomial::new(vec![BigInt::from(3), BigInt::from(0), BigInt::from(1)]);
+
+        let inverse = ring.find_inverse_in_galois_ring(&elem).unwrap();
+
+        assert_eq!(ring.mul_ring(&elem, &inverse), GaloisRing::one());
+    }
+
+    #[test]
+    fn test_inverse_in_galois_ring_of_characteristic_two_power() {
+        let ring = ring_2_64();
+        assert_eq!(ring.prime(), &BigInt::from(2));
+        assert_eq!(ring.exponent(), 64);
+
+        // 1 + 2x + x^3 is a unit: it is x^3 + 1 modulo 2, which is coprime to x^4 + x + 1
+        let elem = Polynomial::new(vec![
+            BigInt::from(1),
+            BigInt::from(2),
+            BigInt::from(0),
+            BigInt::from(1),
+        ]);
+        let inverse = ring.find_inverse_in_galois_ring(&elem).unwrap();
+        assert_eq!(ring.mul_ring(&elem, &inverse), GaloisRing::one());
+
+        // 2 + 2x is divisible by 2 and therefore not a unit
+        let non_unit = Polynomial::new(vec![BigInt::from(2), BigInt::from(2)]);
+        assert!(!ring.is_unit(&non_unit));
+        assert_eq!(
+            ring.find_inverse_in_galois_ring(&non_unit),
+            Err(Error::NonUnit)
+        );
+    }
+
+    #[test]
+    fn test_reduction_modulo_monic_polynomial_over_prime_power() {
+        let ring = GaloisRing::new(BigInt::from(8), irreducible_polynomial(5).unwrap()).unwrap();
+        let x = Polynomial::new(vec![BigInt::zero(), BigInt::one()]);
+
+        // x^5 = -x^2 - 1 = 7 + 7x^2 modulo 8 and x^5 + x^2 + 1
+        assert_eq!(
+            ring.power_in_ring(&x, 5),
+            Polynomial::new(vec![BigInt::from(7), BigInt::zero(), BigInt::from(7)])
+        );
+
+        // Reducing a product of degree 2d - 2 takes more than five division steps
+        let big = ring.power_in_ring(&x, 8);
+        let expected = ring.mul_ring(&ring.power_in_ring(&x, 4), &ring.power_in_ring(&x, 4));
+        assert_eq!(big, expected);
+        assert!(big.degree() < 5);
+    }
+
+    #[test]
+    fn test_rejects_invalid_ring_parameters() {
+        let irreducible = irreducible_polynomial(3).unwrap();
+        assert!(matches!(
+            GaloisRing::new(BigInt::from(12), irreducible.clone()),
+            Err(Error::InvalidRingParameters(_))
+        ));
+        // x^2 + x + 1 has the root 2 modulo 7
+        assert!(matches!(
+            GaloisRing::new(BigInt::from(7), irreducible_polynomial(2).unwrap()),
+            Err(Error::InvalidRingParameters(_))
+        ));
+
+        let ring = GaloisRing::new(BigInt::from(8), irreducible).unwrap();
+        let too_long = Polynomial::new(vec![BigInt::one(); 4]);
+        assert_eq!(ring.validate_element(&too_long), Err(Error::MismatchedRing));
+        assert_eq!(
+            ring.polynomial_long_division(&too_long, &Polynomial::constant(BigInt::from(2))),
+            Err(Error::NonUnit)
+        );
+    }
+}
@@ -0,0 +1,347 @@
+//! Hyper-invertible matrices and batched random double sharings.
+//!
+//! A matrix is hyper-invertible if every square submatrix is invertible.
+//! Over `GR(p^k, d)`, the matrix that maps the values of a polynomial of
+//! degree below `n` at points `a_1, ..., a_n` to its values at points
+//! `b_1, ..., b_n` is hyper-invertible when all `2n` points together form an
+//! exceptional set, which needs `p^d >= 2n`.
+//!
+//! Applying such a matrix to `n` values of which at most `m` are chosen by
+//! the adversary yields `n - m` values that are uniformly random and unknown
+//! to it. [`DoubleSharingPlan`] uses this to turn one random double sharing
+//! dealt by each party into `n - t + 1` random double sharings.
+
+use num_bigint::BigInt;
+use rand::{CryptoRng, RngCore};
+
+use crate::coefficient::Coefficient;
+use crate::error::{check_count, Error, Result};
+use crate::galois_ring::GaloisRing;
+use crate::polynomial::Polynomial;
+use crate::reconstruction::ReconstructionPlan;
+use crate::sharing::{Share, SharingPlan};
+
+/// An `n x n` hyper-invertible matrix over a Galois ring.
+#[derive(Debug, Clone)]
+pub struct HyperInvertibleMatrix<C: Coefficient = BigInt> {
+    ring: GaloisRing<C>,
+    rows: Vec<Vec<Polynomial<C>>>,
+}
+
+impl<C: Coefficient> HyperInvertibleMatrix<C> {
+    /// The `n x n` matrix on the first `2n` non-zero exceptional points.
+    ///
+    /// Fails with [`Error::TooManyParties`] if the ring has fewer than `2n`
+    /// non-zero exceptional points.
+    pub fn new(ring: &GaloisRing<C>, n: usize) -> Result<Self> {
+        let mut points = ring.exceptional_points(2 * n)?;
+        let outputs = points.split_off(n);
+        HyperInvertibleMatrix::from_points(ring, points, &outputs)
+    }
+
+    /// The matrix whose row `i` holds the Lagrange coefficients of `inputs`
+    /// at `outputs[i]`, so that entry `(i, j)` is
+    /// `prod_{l != j} (b_i - a_l) / (a_j - a_l)`.
+    ///
+    /// Fails with [`Error::NotExceptional`] unless there are as many inputs
+    /// as outputs and all of them together form an exceptional set.
+    pub fn from_points(
+        ring: &GaloisRing<C>,
+        inputs: Vec<Polynomial<C>>,
+        outputs: &[Polynomial<C>],
+    ) -> Result<Self> {
+        let all: Vec<_> = inputs.iter().chain(outputs).cloned().collect();
+        if inputs.len() != outputs.len() || !ring.is_exceptional_set(&all) {
+            return Err(Error::NotExceptional);
+        }
+        let rows = outputs
+            .iter()
+            .map(|b| {
+                let plan = ReconstructionPlan::at(ring, inputs.clone(), b)?;
+                Ok(plan.lagrange_coefficients().to_vec())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(HyperInvertibleMatrix {
+            ring: ring.clone(),
+            rows,
+        })
+    }
+
+    /// Number of rows and columns.
+    pub fn size(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// The entries, row by row.
+    pub fn rows(&self) -> &[Vec<Polynomial<C>>] {
+        &self.rows
+    }
+
+    /// The product of the matrix with the column vector `values`.
+    ///
+    /// Fails with [`Error::InsufficientShares`] or [`Error::WrongShareCount`]
+    /// unless there is one value per column.
+    pub fn apply(&self, values: &[Polynomial<C>]) -> Result<Vec<Polynomial<C>>> {
+        check_count(self.size(), values.len())?;
+        for value in values {
+            self.ring.validate_element(value)?;
+        }
+        Ok(self
+            .rows
+            .iter()
+            .map(|row| {
+                let mut sum = GaloisRing::zero();
+                for (entry, value) in row.iter().zip(values) {
+                    sum = self.ring.add(&sum, &self.ring.mul(entry, value));
+                }
+                self.ring.reduce(&sum)
+            })
+            .collect())
+    }
+
+    /// Whether the square submatrix on `rows` and `columns` is invertible.
+    ///
+    /// Over the local ring `GR(p^k, d)` a matrix is invertible exactly when
+    /// Gaussian elimination finds a unit pivot in every column, i.e. when it
+    /// is invertible modulo `p`.
+    pub fn submatrix_is_invertible(&self, rows: &[usize], columns: &[usize]) -> bool {
+        if rows.len() != columns.len() {
+            return false;
+        }
+        let mut matrix: Vec<Vec<_>> = rows
+            .iter()
+            .map(|&i| columns.iter().map(|&j| self.rows[i][j].clone()).collect())
+            .collect();
+        let size = matrix.len();
+        for col in 0..size {
+            let Some(pivot) = (col..size).find(|&r| self.ring.is_unit(&matrix[r][col])) else {
+                return false;
+            };
+            matrix.swap(col, pivot);
+            let Ok(inverse) = self.ring.find_inverse_in_galois_ring(&matrix[col][col]) else {
+                return false;
+            };
+            let (upper, lower) = matrix.split_at_mut(col + 1);
+            let pivot_row = &upper[col];
+            for row in lower {
+                let factor = self.ring.mul_ring(&row[col], &inverse);
+                for (entry, pivot) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                    *entry = self
+                        .ring
+                        .sub_mod(entry, &self.ring.mul_ring(&factor, pivot));
+                }
+            }
+        }
+        true
+    }
+
+    /// Whether every square submatrix is invertible.
+    ///
+    /// This checks all `sum_m C(n, m)^2` submatrices, so it is only practical
+    /// for small `n`.
+    pub fn is_hyper_invertible(&self) -> bool {
+        let subsets = subsets(self.size());
+        subsets.iter().all(|rows| {
+            subsets
+                .iter()
+                .filter(|columns| columns.len() == rows.len())
+                .all(|columns| self.submatrix_is_invertible(rows, columns))
+        })
+    }
+}
+
+/// All non-empty subsets of `0..n`, as sorted index lists.
+fn subsets(n: usize) -> Vec<Vec<usize>> {
+    (1..1usize << n)
+        .map(|mask| (0..n).filter(|i| mask >> i & 1 == 1).collect())
+        .collect()
+}
+
+/// One party's shares of a random value `r` with thresholds `t` and `2t - 1`.
+///
+/// The high-threshold sharing has the degree `2t - 2` of a product of two
+/// threshold-`t` sharings, so it can mask products during degree reduction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoubleSharing<C: Coefficient = BigInt> {
+    pub low: Share<C>,
+    pub high: Share<C>,
+}
+
+/// Batched generation of random double sharings among `n >= 2t - 1` parties
+/// at the first `n` exceptional points.
+///
+/// Each party deals a double sharing of its own random value, and each party
+/// applies an `n x n` hyper-invertible matrix to the shares it received.
+/// With at most `t - 1` passively corrupted parties, the first `n - t + 1`
+/// resulting double sharings are of values uniformly random and unknown to
+/// the adversary.
+#[derive(Debug, Clone)]
+pub struct DoubleSharingPlan<C: Coefficient = BigInt> {
+    ring: GaloisRing<C>,
+    matrix: HyperInvertibleMatrix<C>,
+    low: SharingPlan<C>,
+    high: SharingPlan<C>,
+    outputs: usize,
+}
+
+impl<C: Coefficient> DoubleSharingPlan<C> {
+    /// Plan double sharings with thresholds `t` and `2t - 1` among `n` parties.
+    ///
+    /// Fails with [`Error::InvalidThreshold`] unless `1 <= t` and
+    /// `2t - 1 <= n`, and with [`Error::TooManyParties`] if the ring has fewer
+    /// than `2n` non-zero exceptional points.
+    pub fn new(ring: &GaloisRing<C>, n: usize, t: usize) -> Result<Self> {
+        if t == 0 || 2 * t - 1 > n {
+            return Err(Error::InvalidThreshold {
+                threshold: t,
+                parties: n,
+            });
+        }
+        let points = ring.exceptional_points(n)?;
+        Ok(DoubleSharingPlan {
+            ring: ring.clone(),
+            matrix: HyperInvertibleMatrix::new(ring, n)?,
+            low: SharingPlan::new(ring, points.clone(), t)?,
+            high: SharingPlan::new(ring, points, 2 * t - 1)?,
+            outputs: n - t + 1,
+        })
+    }
+
+    /// The evaluation points, in party order.
+    pub fn points(&self) -> &[Polynomial<C>] {
+        self.low.points()
+    }
+
+    /// Number of double sharings produced per run, `n - t + 1`.
+    pub fn output_count(&self) -> usize {
+        self.outputs
+    }
+
+    /// One party's first step: a double sharing of a fresh random value,
+    /// whose `j`-th pair of shares goes to party `j`.
+    pub fn deal_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Result<Vec<DoubleSharing<C>>> {
+        let secret = self.ring.random_ring_element_with_rng(rng);
+        let low = self.low.share(secret.clone(), rng)?;
+        let high = self.high.share(secret, rng)?;
+        Ok(low
+            .into_iter()
+            .zip(high)
+            .map(|(low, high)| DoubleSharing { low, high })
+            .collect())
+    }
+
+    /// One party's second step: its shares of the output double sharings,
+    /// from the low and high share values received from every party in order.
+    pub fn combine(
+        &self,
+        point: &Polynomial<C>,
+        low: &[Polynomial<C>],
+        high: &[Polynomial<C>],
+    ) -> Result<Vec<DoubleSharing<C>>> {
+        self.ring.validate_element(point)?;
+        let low = self.matrix.apply(low)?;
+        let high = self.matrix.apply(high)?;
+        Ok(low
+            .into_iter()
+            .zip(high)
+            .take(self.outputs)
+            .map(|(low, high)| DoubleSharing {
+                low: Share::from_parts(point.clone(), low),
+                high: Share::from_parts(point.clone(), high),
+            })
+            .collect())
+    }
+
+    /// Run one batch for all parties at once, returning each party's shares
+    /// of the `n - t + 1` double sharings.
+    pub fn generate(&self) -> Result<Vec<Vec<DoubleSharing<C>>>> {
+        self.generate_with_rng(&mut rand::thread_rng())
+    }
+
+    /// [`DoubleSharingPlan::generate`] with randomness from `rng`.
+    pub fn generate_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Result<Vec<Vec<DoubleSharing<C>>>> {
+        let dealt = (0..self.points().len())
+            .map(|_| self.deal_with_rng(rng))
+            .collect::<Result<Vec<_>>>()?;
+        self.points()
+            .iter()
+            .enumerate()
+            .map(|(j, point)| {
+                let low: Vec<_> = dealt.iter().map(|d| d[j].low.value().clone()).collect();
+                let high: Vec<_> = dealt.iter().map(|d| d[j].high.value().clone()).collect();
+                self.combine(point, &low, &high)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::irreducible::irreducible_polynomial;
+
+    #[test]
+    fn test_matrix_is_hyper_invertible() {
+        let ring =
+            GaloisRing::new(BigInt::from(1) << 16, irreducible_polynomial(4).unwrap()).unwrap();
+        let narrow = GaloisRing::<u32>::from_bigint_ring(&ring).unwrap();
+
+        let matrix = HyperInvertibleMatrix::new(&narrow, 5).unwrap();
+        assert!(matrix.is_hyper_invertible());
+        assert_eq!(
+            matrix.apply(&vec![GaloisRing::one(); 6]),
+            Err(Error::WrongShareCount {
+                expected: 5,
+                provided: 6
+            })
+        );
+        assert!(matches!(
+            HyperInvertibleMatrix::new(&narrow, 8),
+            Err(Error::TooManyParties { requested: 16, .. })
+        ));
+
+        // 1 and 3 differ by 2, which is not a unit
+        let points: Vec<_> = (1..=4).map(|i| Polynomial::constant(i as u32)).collect();
+        assert_eq!(
+            HyperInvertibleMatrix::from_points(&narrow, points[..2].to_vec(), &points[2..]).err(),
+            Some(Error::NotExceptional)
+        );
+    }
+
+    #[test]
+    fn test_random_double_sharings() {
+        let ring = GaloisRing::from_parameters(BigInt::from(3), 5, 2).unwrap();
+        let (n, t) = (4, 2);
+        let plan = DoubleSharingPlan::new(&ring, n, t).unwrap();
+        assert_eq!(plan.output_count(), 3);
+
+        let shares = plan.generate().unwrap();
+        assert!(shares.iter().all(|party| party.len() == 3));
+        for k in 0..plan.output_count() {
+            let low: Vec<_> = shares.iter().map(|party| party[k].low.clone()).collect();
+            let high: Vec<_> = shares.iter().map(|party| party[k].high.clone()).collect();
+            let r = ring
+                .reconstruct_secret(high[..2 * t - 1].to_vec(), 2 * t - 1)
+                .unwrap();
+            assert_eq!(
+                ring.reconstruct_secret(high[1..].to_vec(), 2 * t - 1),
+                Ok(r.clone())
+            );
+            assert_eq!(ring.reconstruct_secret(low[..t].to_vec(), t), Ok(r.clone()));
+            assert_eq!(ring.reconstruct_secret(low[2..].to_vec(), t), Ok(r));
+        }
+        assert_eq!(
+            DoubleSharingPlan::new(&ring, 4, 3).err(),
+            Some(Error::InvalidThreshold {
+                threshold: 3,
+                parties: 4
+            })
+        );
+    }
+}
@@ -0,0 +1,311 @@
+use num_bigint::{BigInt, RandBigInt};
+use num_traits::{One, ToPrimitive, Zero};
+
+use crate::error::{Error, Result};
+use crate::galois_ring::GaloisRing;
+use crate::number_theory::{is_probable_prime, prime_factors};
+use crate::polynomial::Polynomial;
+
+/// Largest coefficient tried for the non-unit terms of sparse candidates.
+const SPARSE_COEFFICIENT_BOUND: u32 = 8;
+
+/// A binary irreducible polynomial of the given degree, or `None` for degree 0.
+///
+/// The polynomial is irreducible modulo 2, so it defines `GR(2^k, degree)`
+/// for every `k`. For degrees 2 to 5 these are the familiar `x^2 + x + 1`,
+/// `x^3 + x + 1`, `x^4 + x + 1` and `x^5 + x^2 + 1`.
+pub fn irreducible_polynomial(degree: usize) -> Option<Polynomial> {
+    find_irreducible_polynomial(&BigInt::from(2), degree, false).ok()
+}
+
+/// A monic irreducible polynomial of degree `degree` over `F_p`.
+///
+/// The coefficients lie in `[0, p)`, so the same polynomial is basic
+/// irreducible over `Z/p^kZ` for every exponent `k` and needs no lifting.
+///
+/// Binomials, trinomials and pentanomials with small coefficients are tried
+/// first, since sparse defining polynomials make reduction cheap; if none is
+/// found, random dense polynomials are drawn until one passes. With
+/// `primitive`, the polynomial is also required to be primitive, i.e. `x`
+/// generates the multiplicative group of `F_p[x] / (f)`, which needs the
+/// factorization of `p^degree - 1`.
+///
+/// Fails if `prime` is not prime or `degree` is zero, and with
+/// [`Error::FactoringLimitExceeded`] if `primitive` is set and
+/// `p^degree - 1` cannot be factored within
+/// [`FACTORING_STEP_LIMIT`](crate::number_theory::FACTORING_STEP_LIMIT).
+pub fn find_irreducible_polynomial(
+    prime: &BigInt,
+    degree: usize,
+    primitive: bool,
+) -> Result<Polynomial> {
+    if !is_probable_prime(prime) {
+        return Err(Error::InvalidRingParameters(format!(
+            "{} is not prime",
+            prime
+        )));
+    }
+    if degree == 0 {
+        return Err(Error::InvalidRingParameters(
+            "the degree must be at least 1".into(),
+        ));
+    }
+
+    // The order of the multiplicative group and its prime factors, factored once
+    let group = if primitive {
+        let order: BigInt = prime.pow(degree as u32) - 1;
+        let factors = prime_factors(&order)?;
+        Some((order, factors))
+    } else {
+        None
+    };
+    let accept = |poly: &Polynomial| {
+        is_irreducible_mod_p(poly, prime)
+            && group
+                .as_ref()
+                .is_none_or(|(order, factors)| has_order(poly, prime, order, factors))
+    };
+
+    if let Some(poly) = sparse_candidates(prime, degree).find(|poly| accept(poly)) {
+        return Ok(poly);
+    }
+
+    let mut rng = rand::thread_rng();
+    loop {
+        let mut coeffs: Vec<BigInt> = (0..degree)
+            .map(|_| rng.gen_bigint_range(&BigInt::zero(), prime))
+            .collect();
+        coeffs.push(BigInt::one());
+        let poly = Polynomial::new(coeffs);
+        if accept(&poly) {
+            return Ok(poly);
+        }
+    }
+}
+
+/// Binomials `x^d + b`, then trinomials `x^d + a x^i + b`, then pentanomials
+/// `x^d + x^i + x^j + x^l + b`, with small coefficients `a` and `b`.
+fn sparse_candidates(prime: &BigInt, degree: usize) -> impl Iterator<Item = Polynomial> {
+    let bound = prime.to_u32().map_or(SPARSE_COEFFICIENT_BOUND, |p| {
+        (p - 1).min(SPARSE_COEFFICIENT_BOUND)
+    });
+    // The constant term may only be zero for the polynomial x itself
+    let constants = move || (u32::from(degree > 1)..=bound).map(BigInt::from);
+    let coefficients = move || (1..=bound).map(BigInt::from);
+
+    let sparse_poly = move |terms: Vec<(usize, BigInt)>| {
+        let mut coeffs = vec![BigInt::zero(); degree + 1];
+        coeffs[degree] = BigInt::one();
+        for (exponent, coeff) in terms {
+            coeffs[exponent] = coeff;
+        }
+        Polynomial::new(coeffs)
+    };
+
+    let binomials = constants().map(move |b| sparse_poly(vec![(0, b)]));
+    let trinomials = (1..degree).flat_map(move |i| {
+        coefficients()
+            .flat_map(move |a| constants().map(move |b| sparse_poly(vec![(i, a.clone()), (0, b)])))
+    });
+    let pentanomials = (3..degree).flat_map(move |i| {
+        (2..i).flat_map(move |j| {
+            (1..j).flat_map(move |l| {
+                constants().map(move |b| {
+                    sparse_poly(vec![
+                        (i, BigInt::one()),
+                        (j, BigInt::one()),
+                        (l, BigInt::one()),
+                        (0, b),
+                    ])
+                })
+            })
+        })
+    });
+
+    binomials.chain(trinomials).chain(pentanomials)
+}
+
+/// Rabin's irreducibility test for a monic polynomial `f` of degree `d` over `F_p`.
+///
+/// `f` is irreducible if and only if `x^(p^d) = x` modulo `f` and
+/// `gcd(x^(p^(d/q)) - x, f) = 1` for every prime `q` dividing `d`.
+pub fn is_irreducible_mod_p(poly: &Polynomial, prime: &BigInt) -> bool {
+    let Some(poly) = monic_mod_p(poly, prime) else {
+        return false;
+    };
+    let degree = poly.degree();
+    if degree == 0 {
+        return false;
+    }
+    if degree == 1 {
+        return true;
+    }
+
+    let field = GaloisRing::new_unchecked(prime.clone(), 1, poly.clone());
+    let x = Polynomial::new(vec![BigInt::zero(), BigInt::one()]);
+
+    // A usize has no prime factors beyond the reach of the step limit
+    let degree_factors = prime_factors(&BigInt::from(degree)).unwrap_or_default();
+    for q in degree_factors {
+        let q = q.to_usize().unwrap();
+        let frobenius = frobenius_power(&field, &x, degree / q);
+        let difference = field.sub_mod(&frobenius, &x);
+        match field.extended_euclidean(&poly, &difference) {
+            Ok((gcd, _, _)) if gcd.trimmed_poly().degree() == 0 => {}
+            // A common factor, or a non-unit leading coefficient because p is not prime
+            _ => return false,
+        }
+    }
+
+    frobenius_power(&field, &x, degree) == x
+}
+
+/// Whether the irreducible polynomial `f` is primitive over `F_p`, i.e. the
+/// class of `x` has multiplicative order `p^d - 1`.
+///
+/// Fails with [`Error::FactoringLimitExceeded`] if `p^d - 1` cannot be
+/// factored within [`FACTORING_STEP_LIMIT`](crate::number_theory::FACTORING_STEP_LIMIT).
+pub fn is_primitive_mod_p(poly: &Polynomial, prime: &BigInt) -> Result<bool> {
+    let Some(monic) = monic_mod_p(poly, prime) else {
+        return Ok(false);
+    };
+    let order: BigInt = prime.pow(monic.degree() as u32) - 1;
+    let factors = prime_factors(&order)?;
+    Ok(has_order(&monic, prime, &order, &factors))
+}
+
+/// Whether `x` has multiplicative order exactly `order` modulo `f` and `p`,
+/// given the distinct prime `factors` of `order`.
+fn has_order(poly: &Polynomial, prime: &BigInt, order: &BigInt, factors: &[BigInt]) -> bool {
+    let Some(poly) = monic_mod_p(poly, prime) else {
+        return false;
+    };
+    let field = GaloisRing::new_unchecked(prime.clone(), 1, poly);
+    let x = Polynomial::new(vec![BigInt::zero(), BigInt::one()]);
+
+    // x must be a unit of order dividing p^d - 1, and of no smaller order
+    field.pow_windowed(&x, order.magnitude(), 4) == GaloisRing::one()
+        && factors
+            .iter()
+            .all(|q| field.pow_windowed(&x, (order / q).magnitude(), 4) != GaloisRing::one())
+}
+
+/// The monic associate of `poly` modulo `p`, or `None` if it vanishes modulo `p`
+/// or its leading coefficient is not invertible.
+fn monic_mod_p(poly: &Polynomial, prime: &BigInt) -> Option<Polynomial> {
+    let mut reduced = Polynomial::new(
+        poly.coeffs
+            .iter()
+            .map(|c| ((c % prime) + prime) % prime)
+            .collect(),
+    );
+    reduced.trim();
+    let leading = reduced.coeffs.last()?;
+    let leading_inverse = leading.modinv(prime)?;
+    Some(Polynomial::new(
+        reduced
+            .coeffs
+            .iter()
+            .map(|c| (c * &leading_inverse) % prime)
+            .collect(),
+    ))
+}
+
+/// `elem^(p^count)`, applying the Frobenius map `count` times.
+fn frobenius_power(field: &GaloisRing, elem: &Polynomial, count: usize) -> Polynomial {
+    let mut result = elem.clone();
+    for _ in 0..count {
+        result = field.pow(&result, field.prime().magnitude());
+    }
+    result
+}
+
+impl GaloisRing {
+    /// Build `GR(p^k, d)` with an automatically chosen basic irreducible
+    /// polynomial of degree `d`.
+    pub fn from_parameters(prime: BigInt, exponent: u32, degree: usize) -> Result<Self> {
+        let irreducible = find_irreducible_polynomial(&prime, degree, false)?;
+        GaloisRing::from_prime_power(prime, exponent, irreducible)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poly(coeffs: &[i64]) -> Polynomial {
+        Polynomial::new(coeffs.iter().map(|&c| BigInt::from(c)).collect())
+    }
+
+    #[test]
+    fn test_irreducible_polynomial_matches_known_binary_polynomials() {
+        assert_eq!(irreducible_polynomial(2), Some(poly(&[1, 1, 1])));
+        assert_eq!(irreducible_polynomial(3), Some(poly(&[1, 1, 0, 1])));
+        assert_eq!(irreducible_polynomial(4), Some(poly(&[1, 1, 0, 0, 1])));
+        assert_eq!(irreducible_polynomial(5), Some(poly(&[1, 0, 1, 0, 0, 1])));
+        assert_eq!(irreducible_polynomial(0), None);
+    }
+
+    #[test]
+    fn test_rabin_test() {
+        let two = BigInt::from(2);
+        // x^5 + x + 1 = (x^2 + x + 1)(x^3 + x^2 + 1) over F_2
+        assert!(!is_irreducible_mod_p(&poly(&[1, 1, 0, 0, 0, 1]), &two));
+        // x^4 + x^2 + 1 = (x^2 + x + 1)^2 over F_2 has no roots
+        assert!(!is_irreducible_mod_p(&poly(&[1, 0, 1, 0, 1]), &two));
+        assert!(is_irreducible_mod_p(&poly(&[1, 0, 1]), &BigInt::from(3)));
+        assert!(!is_irreducible_mod_p(&poly(&[1, 0, 1]), &BigInt::from(5)));
+    }
+
+    #[test]
+    fn test_find_irreducible_polynomial_for_any_degree() {
+        for prime in [2u32, 3, 5, 65537] {
+            let prime = BigInt::from(prime);
+            for degree in [1, 6, 8, 13] {
+                let poly = find_irreducible_polynomial(&prime, degree, false).unwrap();
+                assert_eq!(poly.degree(), degree);
+                assert!(is_irreducible_mod_p(&poly, &prime));
+            }
+        }
+    }
+
+    #[test]
+    fn test_primitive_polynomial() {
+        let two = BigInt::from(2);
+        // x^4 + x^3 + x^2 + x + 1 is irreducible but x has order 5
+        assert_eq!(is_primitive_mod_p(&poly(&[1, 1, 1, 1, 1]), &two), Ok(false));
+
+        let poly = find_irreducible_polynomial(&BigInt::from(3), 6, true).unwrap();
+        assert_eq!(is_primitive_mod_p(&poly, &BigInt::from(3)), Ok(true));
+
+        // p - 1 = 2 * 3 * 7 * (2^61 - 1) * q for a second 61-bit prime q
+        let p: BigInt = "223310303291865863548786581743893218583".parse().unwrap();
+        assert_eq!(
+            find_irreducible_polynomial(&p, 1, true),
+            Err(Error::FactoringLimitExceeded)
+        );
+        assert!(find_irreducible_polynomial(&p, 1, false).is_ok());
+    }
+
+    #[test]
+    fn test_ring_from_parameters() {
+        let ring = GaloisRing::from_parameters(BigInt::from(2), 64, 12).unwrap();
+        let secret = ring.random_ring_element();
+
+        let shares = ring.shamir_secret_sharing(secret.clone(), 5, 3).unwrap();
+
+        assert_eq!(ring.reconstruct_secret(shares[2..].to_vec(), 3), Ok(secret));
+    }
+
+    #[test]
+    fn test_find_irreducible_polynomial_rejects_invalid_parameters() {
+        assert!(matches!(
+            find_irreducible_polynomial(&BigInt::from(4), 3, false),
+            Err(Error::InvalidRingParameters(_))
+        ));
+        assert!(matches!(
+            find_irreducible_polynomial(&BigInt::from(3), 0, false),
+            Err(Error::InvalidRingParameters(_))
+        ));
+    }
+}
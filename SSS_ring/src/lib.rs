@@ -0,0 +1,73 @@
+//! Shamir secret sharing over Galois ring extensions.
+//!
+//! Secrets and shares are elements of the Galois ring
+//! `GR(p^k, d) = (Z/p^kZ)[x] / (h(x))`, represented as
+//! [`Polynomial`]s of degree below `deg(h)`. Parties receive evaluations of a
+//! random sharing polynomial at points of an exceptional set, and any
+//! sufficiently large set of shares recovers the secret by Lagrange
+//! interpolation.
+//!
+//! - [`coefficient`]: `BigInt` and fixed-width `u64`/`u32` coefficient backends.
+//! - [`polynomial`]: the coefficient-vector representation of ring elements.
+//! - [`galois_ring`]: ring arithmetic and inversion.
+//! - [`power`]: exponentiation with arbitrary-size exponents.
+//! - [`number_theory`]: primality and prime-power helpers for the modulus.
+//! - [`irreducible`]: defining polynomials for the extension.
+//! - [`exceptional_set`]: evaluation points with invertible differences.
+//! - [`error`]: the crate-wide error type.
+//! - [`sharing`]: dealing shares of a secret.
+//! - [`linear`]: local linear operations on shares.
+//! - [`multiplication`]: BGW multiplication of shared secrets.
+//! - [`beaver`]: dealer-generated triples and Beaver multiplication.
+//! - [`packed`]: Franklin–Yung packed sharing of several secrets at once.
+//! - [`reconstruction`]: recovering a secret from shares.
+//! - [`error_correction`]: recovering a secret despite corrupted shares.
+//! - [`mac`]: SPDZ2k-style MACs for actively secure computation over `2^k`.
+//! - [`hyper_invertible`]: hyper-invertible matrices and random double sharings.
+//! - [`rmfe`]: embeddings of `Z/p^k` vectors for batched multiplication.
+//! - [`network`]: an in-process network for running protocols per party.
+//! - [`codec`]: byte strings as sequences of ring elements.
+//! - [`refresh`]: proactive re-randomization of shares between epochs.
+//! - [`resharing`]: moving a sharing to a new committee and threshold.
+//! - [`serialization`]: the versioned binary format for rings and shares.
+
+mod arithmetic;
+pub mod beaver;
+pub mod codec;
+pub mod coefficient;
+pub mod error;
+pub mod error_correction;
+pub mod exceptional_set;
+pub mod galois_ring;
+pub mod hyper_invertible;
+pub mod irreducible;
+pub mod linear;
+pub mod mac;
+pub mod multiplication;
+pub mod network;
+pub mod number_theory;
+pub mod packed;
+pub mod polynomial;
+pub mod power;
+pub mod reconstruction;
+pub mod refresh;
+pub mod resharing;
+pub mod rmfe;
+pub mod serialization;
+pub mod sharing;
+#[cfg(test)]
+mod test_utils;
+
+pub use beaver::{Preprocessing, Triple};
+pub use coefficient::Coefficient;
+pub use error::{Error, Result};
+pub use galois_ring::{GaloisRing, GaloisRing32, GaloisRing64};
+pub use hyper_invertible::{DoubleSharing, DoubleSharingPlan, HyperInvertibleMatrix};
+pub use irreducible::irreducible_polynomial;
+pub use mac::{AuthenticatedShare, Spdz2k};
+pub use multiplication::MultiplicationPlan;
+pub use polynomial::Polynomial;
+pub use reconstruction::ReconstructionPlan;
+pub use rmfe::{ReencodingPair, Rmfe};
+pub use serialization::ShareRecord;
+pub use sharing::{Share, SharingPlan};
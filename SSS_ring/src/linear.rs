@@ -0,0 +1,177 @@
+use crate::coefficient::Coefficient;
+use crate::error::{Error, Result};
+use crate::galois_ring::GaloisRing;
+use crate::polynomial::Polynomial;
+use crate::sharing::Share;
+
+impl<C: Coefficient> Share<C> {
+    /// A share of `a + b` from one party's shares of `a` (`self`) and `b`.
+    ///
+    /// Sharing is linear, so every party can compute this locally. Fails with
+    /// [`Error::MismatchedPoint`] if the shares have different evaluation
+    /// points and with [`Error::MismatchedRing`] if either is not a reduced
+    /// element of `ring`.
+    ///
+    /// Shares do not record their ring, so a share from a ring with the same
+    /// degree and a smaller modulus passes this check; keeping the rings
+    /// apart is up to the caller.
+    pub fn add(&self, other: &Share<C>, ring: &GaloisRing<C>) -> Result<Share<C>> {
+        self.check_same_point(other, ring)?;
+        Ok(Share::from_parts(
+            self.point().clone(),
+            ring.add_ring(self.value(), other.value()),
+        ))
+    }
+
+    /// A share of `a - b`; see [`Share::add`].
+    pub fn sub(&self, other: &Share<C>, ring: &GaloisRing<C>) -> Result<Share<C>> {
+        self.check_same_point(other, ring)?;
+        Ok(Share::from_parts(
+            self.point().clone(),
+            ring.sub_mod(self.value(), other.value()),
+        ))
+    }
+
+    /// A share of `c * a` for a public ring element `c`.
+    pub fn mul_constant(&self, c: &Polynomial<C>, ring: &GaloisRing<C>) -> Result<Share<C>> {
+        self.validate(ring)?;
+        ring.validate_element(c)?;
+        Ok(Share::from_parts(
+            self.point().clone(),
+            ring.mul_ring(self.value(), c),
+        ))
+    }
+
+    /// A share of `a + c` for a public ring element `c`.
+    ///
+    /// Every party adds `c` to its value, which shifts the constant term of
+    /// the sharing polynomial by `c`.
+    pub fn add_constant(&self, c: &Polynomial<C>, ring: &GaloisRing<C>) -> Result<Share<C>> {
+        self.validate(ring)?;
+        ring.validate_element(c)?;
+        Ok(Share::from_parts(
+            self.point().clone(),
+            ring.add_ring(self.value(), c),
+        ))
+    }
+
+    /// A share of `constant + sum c_i a_i` from one party's shares `a_i` and
+    /// public coefficients `c_i`.
+    ///
+    /// Fails if `terms` is empty, and otherwise like the individual operations.
+    pub fn linear_combination(
+        ring: &GaloisRing<C>,
+        terms: &[(Polynomial<C>, Share<C>)],
+        constant: &Polynomial<C>,
+    ) -> Result<Share<C>> {
+        let (_, first) = terms.first().ok_or(Error::InsufficientShares {
+            required: 1,
+            provided: 0,
+        })?;
+        ring.validate_element(constant)?;
+        let mut value = constant.clone();
+        for (c, share) in terms {
+            first.check_same_point(share, ring)?;
+            ring.validate_element(c)?;
+            value = ring.add(&value, &ring.mul(c, share.value()));
+        }
+        Ok(Share::from_parts(
+            first.point().clone(),
+            ring.reduce(&value),
+        ))
+    }
+
+    /// Checks that the point and the value are reduced elements of `ring`.
+    pub(crate) fn validate(&self, ring: &GaloisRing<C>) -> Result<()> {
+        ring.validate_element(self.point())?;
+        ring.validate_element(self.value())
+    }
+
+    /// Checks that both shares are reduced in `ring` and have the same point.
+    pub(crate) fn check_same_point(&self, other: &Share<C>, ring: &GaloisRing<C>) -> Result<()> {
+        self.validate(ring)?;
+        other.validate(ring)?;
+        if self.point() != other.point() {
+            return Err(Error::MismatchedPoint);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::irreducible::irreducible_polynomial;
+    use crate::test_utils::ring_2_64_u64;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn test_local_linear_function() {
+        let wide = ring_2_64_u64();
+        let (a, b) = (wide.random_ring_element(), wide.random_ring_element());
+        let (c, k) = (wide.random_ring_element(), wide.random_ring_element());
+        let shares_a = wide.shamir_secret_sharing(a.clone(), 5, 3).unwrap();
+        let shares_b = wide.shamir_secret_sharing(b.clone(), 5, 3).unwrap();
+
+        // Each party computes shares of c * (a - b) + k and of a + b + c * a locally
+        let result: Vec<_> = shares_a
+            .iter()
+            .zip(&shares_b)
+            .map(|(sa, sb)| {
+                let difference = sa.sub(sb, &wide).unwrap();
+                let scaled = difference.mul_constant(&c, &wide).unwrap();
+                scaled.add_constant(&k, &wide).unwrap()
+            })
+            .collect();
+        let combined: Vec<_> = shares_a
+            .iter()
+            .zip(&shares_b)
+            .map(|(sa, sb)| {
+                let terms = [(c.clone(), sa.clone()), (GaloisRing::one(), sb.clone())];
+                let sum = Share::linear_combination(&wide, &terms, &GaloisRing::zero()).unwrap();
+                sum.add(sa, &wide).unwrap()
+            })
+            .collect();
+
+        let expected = wide.add_ring(&wide.mul_ring(&c, &wide.sub_mod(&a, &b)), &k);
+        assert_eq!(
+            wide.reconstruct_secret(result[2..].to_vec(), 3),
+            Ok(expected)
+        );
+        let expected = wide.add_ring(&wide.add_ring(&a, &b), &wide.mul_ring(&c, &a));
+        assert_eq!(
+            wide.reconstruct_secret(combined[..3].to_vec(), 3),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn test_rejects_mismatched_shares() {
+        let ring = GaloisRing::new(BigInt::from(8), irreducible_polynomial(3).unwrap()).unwrap();
+        let shares = ring
+            .shamir_secret_sharing(ring.random_ring_element(), 3, 2)
+            .unwrap();
+
+        assert_eq!(
+            shares[0].add(&shares[1], &ring),
+            Err(Error::MismatchedPoint)
+        );
+        assert_eq!(
+            Share::new(
+                &ring,
+                shares[0].point().clone(),
+                Polynomial::constant(BigInt::from(9))
+            ),
+            Err(Error::MismatchedRing)
+        );
+        let foreign = Share::from_parts(
+            shares[0].point().clone(),
+            Polynomial::constant(BigInt::from(9)),
+        );
+        assert_eq!(shares[0].sub(&foreign, &ring), Err(Error::MismatchedRing));
+        assert_eq!(
+            shares[0].mul_constant(&Polynomial::new(vec![BigInt::from(1); 4]), &ring),
+            Err(Error::MismatchedRing)
+        );
+    }
+}
@@ -0,0 +1,453 @@
+//! SPDZ2k-style information-theoretic MACs for sharings over `GR(2^k, d)`.
+//!
+//! Values of the ring `GR(2^k, d)` are shared in the larger ring
+//! `GR(2^(k+s), d)` with the same defining polynomial, where `s` is the
+//! statistical security parameter. A global key `alpha` is Shamir-shared
+//! among the parties, and every value `x` is carried together with a sharing
+//! of its MAC `alpha * x`. Since the MAC relation is linear, it survives the
+//! local operations of [`crate::linear`].
+//!
+//! Local operations such as [`Spdz2k::mul_constant`] leave the upper `s`
+//! bits of a shared value dependent on the inputs, so opening first adds a
+//! fresh authenticated sharing of `2^k r` for a random `r`. The opened
+//! element of `GR(2^(k+s), d)` is then uniform above the value modulo `2^k`.
+//!
+//! Opening a batch of values is followed by a MAC check: with public random
+//! coefficients `chi_j`, the parties open
+//! `z = sum chi_j m_j - alpha * sum chi_j x_j`, which is zero for honest
+//! shares. An adversary that shifts an opened value by a non-zero amount
+//! modulo `2^k` passes the check with probability about `2^-s`.
+//!
+//! The simulation here leaves out the commitments to the shares of `z` that
+//! a real deployment makes before opening it.
+
+use num_bigint::BigInt;
+use num_traits::One;
+use rand::{CryptoRng, RngCore};
+
+use crate::coefficient::Coefficient;
+use crate::error::{check_count, Error, Result};
+use crate::galois_ring::GaloisRing;
+use crate::polynomial::Polynomial;
+use crate::sharing::{Share, SharingPlan};
+
+/// One party's share of a value together with its share of the value's MAC.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthenticatedShare<C: Coefficient = BigInt> {
+    pub value: Share<C>,
+    pub mac: Share<C>,
+}
+
+/// Authenticated sharing of `GR(2^k, d)` values with statistical security
+/// parameter `s`.
+#[derive(Debug, Clone)]
+pub struct Spdz2k<C: Coefficient = BigInt> {
+    base: GaloisRing<C>,
+    ring: GaloisRing<C>,
+    statistical_security: u32,
+}
+
+impl<C: Coefficient> Spdz2k<C> {
+    /// Authenticate values of `base = GR(2^k, d)` with security parameter `s`.
+    ///
+    /// Fails with [`Error::InvalidRingParameters`] if the characteristic of
+    /// `base` is not a power of two, `s` is zero, or the backend `C` cannot
+    /// hold coefficients modulo `2^(k+s)`.
+    pub fn new(base: &GaloisRing<C>, s: u32) -> Result<Self> {
+        if base.prime() != &BigInt::from(2) {
+            return Err(Error::InvalidRingParameters(
+                "MACs need a ring of characteristic 2^k".into(),
+            ));
+        }
+        if s == 0 {
+            return Err(Error::InvalidRingParameters(
+                "the statistical security parameter must be at least 1".into(),
+            ));
+        }
+        let ring = GaloisRing::new_unchecked(
+            base.prime().clone(),
+            base.exponent() + s,
+            base.irreducible().to_bigint(),
+        );
+        Ok(Spdz2k {
+            base: base.clone(),
+            ring: GaloisRing::from_bigint_ring(&ring)?,
+            statistical_security: s,
+        })
+    }
+
+    /// The ring `GR(2^k, d)` of the values.
+    pub fn base_ring(&self) -> &GaloisRing<C> {
+        &self.base
+    }
+
+    /// The ring `GR(2^(k+s), d)` that shares and MACs live in.
+    pub fn ring(&self) -> &GaloisRing<C> {
+        &self.ring
+    }
+
+    /// The statistical security parameter `s`.
+    pub fn statistical_security(&self) -> u32 {
+        self.statistical_security
+    }
+
+    /// The dealer's key generation: a random MAC key and its threshold-`t`
+    /// sharing among `n` parties at the first `n` exceptional points.
+    ///
+    /// The key stays with the dealer; party `i` gets the `i`-th share.
+    pub fn generate_key(&self, n: usize, t: usize) -> Result<(Polynomial<C>, Vec<Share<C>>)> {
+        self.generate_key_with_rng(n, t, &mut rand::thread_rng())
+    }
+
+    /// [`Spdz2k::generate_key`] with randomness from `rng`.
+    pub fn generate_key_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        n: usize,
+        t: usize,
+        rng: &mut R,
+    ) -> Result<(Polynomial<C>, Vec<Share<C>>)> {
+        let key = self.ring.random_ring_element_with_rng(rng);
+        let shares = self.plan(n, t)?.share(key.clone(), rng)?;
+        Ok((key, shares))
+    }
+
+    /// The dealer's input step: authenticated threshold-`t` shares of the
+    /// base ring element `value` under `key`, for `n` parties.
+    ///
+    /// The upper `s` bits of every coefficient of the shared value are
+    /// random, so that opening it reveals nothing beyond `value`.
+    pub fn share(
+        &self,
+        value: &Polynomial<C>,
+        key: &Polynomial<C>,
+        n: usize,
+        t: usize,
+    ) -> Result<Vec<AuthenticatedShare<C>>> {
+        self.share_with_rng(value, key, n, t, &mut rand::thread_rng())
+    }
+
+    /// [`Spdz2k::share`] with randomness from `rng`.
+    pub fn share_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        value: &Polynomial<C>,
+        key: &Polynomial<C>,
+        n: usize,
+        t: usize,
+        rng: &mut R,
+    ) -> Result<Vec<AuthenticatedShare<C>>> {
+        self.base.validate_element(value)?;
+        let plan = self.plan(n, t)?;
+        let noise = self.ring.random_ring_element_with_rng(rng);
+        let shift = Polynomial::constant(BigInt::one() << self.base.exponent());
+        let lifted = self.ring.add_ring(
+            &self.lift(value),
+            &self
+                .ring
+                .mul_ring(&self.ring.element_from_bigint(&shift), &noise),
+        );
+        let mac = self.ring.mul_ring(key, &lifted);
+        let values = plan.share(lifted, rng)?;
+        let macs = plan.share(mac, rng)?;
+        Ok(values
+            .into_iter()
+            .zip(macs)
+            .map(|(value, mac)| AuthenticatedShare { value, mac })
+            .collect())
+    }
+
+    /// A share of `a + b`.
+    pub fn add(
+        &self,
+        a: &AuthenticatedShare<C>,
+        b: &AuthenticatedShare<C>,
+    ) -> Result<AuthenticatedShare<C>> {
+        Ok(AuthenticatedShare {
+            value: a.value.add(&b.value, &self.ring)?,
+            mac: a.mac.add(&b.mac, &self.ring)?,
+        })
+    }
+
+    /// A share of `a - b`.
+    pub fn sub(
+        &self,
+        a: &AuthenticatedShare<C>,
+        b: &AuthenticatedShare<C>,
+    ) -> Result<AuthenticatedShare<C>> {
+        Ok(AuthenticatedShare {
+            value: a.value.sub(&b.value, &self.ring)?,
+            mac: a.mac.sub(&b.mac, &self.ring)?,
+        })
+    }
+
+    /// A share of `c * a` for a public base ring element `c`.
+    pub fn mul_constant(
+        &self,
+        a: &AuthenticatedShare<C>,
+        c: &Polynomial<C>,
+    ) -> Result<AuthenticatedShare<C>> {
+        self.base.validate_element(c)?;
+        let c = self.lift(c);
+        Ok(AuthenticatedShare {
+            value: a.value.mul_constant(&c, &self.ring)?,
+            mac: a.mac.mul_constant(&c, &self.ring)?,
+        })
+    }
+
+    /// A share of `a + c` for a public base ring element `c`, which needs the
+    /// party's `key_share` to update the MAC by `alpha * c`.
+    pub fn add_constant(
+        &self,
+        a: &AuthenticatedShare<C>,
+        c: &Polynomial<C>,
+        key_share: &Share<C>,
+    ) -> Result<AuthenticatedShare<C>> {
+        self.base.validate_element(c)?;
+        let c = self.lift(c);
+        let mac_shift = key_share.mul_constant(&c, &self.ring)?;
+        Ok(AuthenticatedShare {
+            value: a.value.add_constant(&c, &self.ring)?,
+            mac: a.mac.add(&mac_shift, &self.ring)?,
+        })
+    }
+
+    /// One party's share of the check value
+    /// `z = sum chi_j m_j - alpha * sum chi_j x_j` for the values `opened`,
+    /// whose MAC shares the party holds in `shares`.
+    ///
+    /// Fails with [`Error::InsufficientShares`] or [`Error::WrongShareCount`]
+    /// unless there is one opened value and one coefficient per share.
+    pub fn check_share(
+        &self,
+        shares: &[AuthenticatedShare<C>],
+        opened: &[Polynomial<C>],
+        chi: &[Polynomial<C>],
+        key_share: &Share<C>,
+    ) -> Result<Share<C>> {
+        check_count(shares.len(), opened.len())?;
+        check_count(shares.len(), chi.len())?;
+        let mut combined = GaloisRing::zero();
+        for (x, c) in opened.iter().zip(chi) {
+            self.ring.validate_element(x)?;
+            combined = self.ring.add(&combined, &self.ring.mul(c, x));
+        }
+        let combined = self.ring.reduce(&combined);
+        let mut terms: Vec<_> = chi
+            .iter()
+            .cloned()
+            .zip(shares.iter().map(|share| share.mac.clone()))
+            .collect();
+        terms.push((
+            self.ring.sub_mod(&GaloisRing::zero(), &combined),
+            key_share.clone(),
+        ));
+        Share::linear_combination(&self.ring, &terms, &GaloisRing::zero())
+    }
+
+    /// The dealer's preprocessing for [`Spdz2k::open`]: `count` authenticated
+    /// threshold-`t` sharings of `2^k r` for random `r`, among `n` parties.
+    ///
+    /// Entry `[i][j]` is party `i`'s share of the `j`-th mask.
+    pub fn deal_masks(
+        &self,
+        key: &Polynomial<C>,
+        count: usize,
+        n: usize,
+        t: usize,
+    ) -> Result<Vec<Vec<AuthenticatedShare<C>>>> {
+        self.deal_masks_with_rng(key, count, n, t, &mut rand::thread_rng())
+    }
+
+    /// [`Spdz2k::deal_masks`] with randomness from `rng`.
+    pub fn deal_masks_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        key: &Polynomial<C>,
+        count: usize,
+        n: usize,
+        t: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Vec<AuthenticatedShare<C>>>> {
+        let mut dealt = vec![Vec::with_capacity(count); n];
+        for _ in 0..count {
+            // A sharing of zero is a sharing of 2^k times its random upper bits
+            let shares = self.share_with_rng(&GaloisRing::zero(), key, n, t, rng)?;
+            for (party, share) in dealt.iter_mut().zip(shares) {
+                party.push(share);
+            }
+        }
+        Ok(dealt)
+    }
+
+    /// Open a batch of values and check their MACs, running every party's
+    /// steps in turn. `shares[i][j]` is party `i`'s share of the `j`-th value,
+    /// `masks[i][j]` its share of the mask from [`Spdz2k::deal_masks`] added
+    /// to that value before opening, and `key_shares[i]` its share of the
+    /// key. Every mask must be used only once.
+    ///
+    /// Returns the values in the base ring. Fails with
+    /// [`Error::MacCheckFailed`] if the opened values are inconsistent with
+    /// their MACs, and with [`Error::InsufficientShares`] or
+    /// [`Error::WrongShareCount`] unless there is one key share per party and
+    /// one mask per value, and every party holds the same number of shares.
+    pub fn open(
+        &self,
+        shares: &[Vec<AuthenticatedShare<C>>],
+        masks: &[Vec<AuthenticatedShare<C>>],
+        key_shares: &[Share<C>],
+    ) -> Result<Vec<Polynomial<C>>> {
+        self.open_with_rng(shares, masks, key_shares, &mut rand::thread_rng())
+    }
+
+    /// [`Spdz2k::open`] with the check coefficients drawn from `rng`.
+    pub fn open_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        shares: &[Vec<AuthenticatedShare<C>>],
+        masks: &[Vec<AuthenticatedShare<C>>],
+        key_shares: &[Share<C>],
+        rng: &mut R,
+    ) -> Result<Vec<Polynomial<C>>> {
+        check_count(shares.len(), key_shares.len())?;
+        check_count(shares.len(), masks.len())?;
+        let count = shares.first().map_or(0, Vec::len);
+        let shares = shares
+            .iter()
+            .zip(masks)
+            .map(|(party, party_masks)| {
+                check_count(count, party.len())?;
+                check_count(count, party_masks.len())?;
+                party
+                    .iter()
+                    .zip(party_masks)
+                    .map(|(share, mask)| self.add(share, mask))
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Every party's share takes part in each opening
+        let n = shares.len();
+        let opened = (0..count)
+            .map(|j| {
+                let values = shares.iter().map(|party| party[j].value.clone());
+                self.ring.reconstruct_secret(values.collect(), n)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // The coefficients come from a joint coin toss once the values are open
+        let chi: Vec<_> = (0..count)
+            .map(|_| self.ring.random_ring_element_with_rng(rng))
+            .collect();
+        let check = shares
+            .iter()
+            .zip(key_shares)
+            .map(|(party, key_share)| self.check_share(party, &opened, &chi, key_share))
+            .collect::<Result<Vec<_>>>()?;
+        if !self.ring.reconstruct_secret(check, n)?.is_zero() {
+            return Err(Error::MacCheckFailed);
+        }
+        Ok(opened.iter().map(|x| self.truncate(x)).collect())
+    }
+
+    fn plan(&self, n: usize, t: usize) -> Result<SharingPlan<C>> {
+        SharingPlan::new(&self.ring, self.ring.exceptional_points(n)?, t)
+    }
+
+    /// The base ring element `x` as an element of `GR(2^(k+s), d)`.
+    fn lift(&self, x: &Polynomial<C>) -> Polynomial<C> {
+        self.ring.element_from_bigint(&x.to_bigint())
+    }
+
+    /// `x` modulo `2^k`.
+    fn truncate(&self, x: &Polynomial<C>) -> Polynomial<C> {
+        self.base.element_from_bigint(&x.to_bigint())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::irreducible::irreducible_polynomial;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn scheme() -> Spdz2k<u64> {
+        let ring =
+            GaloisRing::new(BigInt::from(1) << 32, irreducible_polynomial(3).unwrap()).unwrap();
+        Spdz2k::new(&GaloisRing::from_bigint_ring(&ring).unwrap(), 32).unwrap()
+    }
+
+    #[test]
+    fn test_open_linear_function_with_mac_check() {
+        let spdz = scheme();
+        let base = spdz.base_ring();
+        let mut rng = StdRng::seed_from_u64(3);
+        let (key, key_shares) = spdz.generate_key_with_rng(5, 3, &mut rng).unwrap();
+        let (x, y) = (base.random_ring_element(), base.random_ring_element());
+        let c = base.random_ring_element();
+        let xs = spdz.share_with_rng(&x, &key, 5, 3, &mut rng).unwrap();
+        let ys = spdz.share_with_rng(&y, &key, 5, 3, &mut rng).unwrap();
+
+        // Every party computes shares of x - y and c x + y + c locally
+        let shares: Vec<Vec<_>> = (0..5)
+            .map(|i| {
+                let scaled = spdz.mul_constant(&xs[i], &c).unwrap();
+                let sum = spdz.add(&scaled, &ys[i]).unwrap();
+                vec![
+                    spdz.sub(&xs[i], &ys[i]).unwrap(),
+                    spdz.add_constant(&sum, &c, &key_shares[i]).unwrap(),
+                ]
+            })
+            .collect();
+
+        let masks = spdz.deal_masks_with_rng(&key, 2, 5, 3, &mut rng).unwrap();
+        let opened = spdz
+            .open_with_rng(&shares, &masks, &key_shares, &mut rng)
+            .unwrap();
+        assert_eq!(opened[0], base.sub_mod(&x, &y));
+        assert_eq!(
+            opened[1],
+            base.add_ring(&base.add_ring(&base.mul_ring(&c, &x), &y), &c)
+        );
+
+        // A party shifting its value share is caught
+        let mut cheating = shares.clone();
+        let shifted = cheating[2][1]
+            .value
+            .add_constant(&GaloisRing::one(), spdz.ring());
+        cheating[2][1].value = shifted.unwrap();
+        let masks = spdz.deal_masks_with_rng(&key, 2, 5, 3, &mut rng).unwrap();
+        assert_eq!(
+            spdz.open_with_rng(&cheating, &masks, &key_shares, &mut rng),
+            Err(Error::MacCheckFailed)
+        );
+        assert_eq!(
+            spdz.open_with_rng(&shares[..4], &masks, &key_shares, &mut rng),
+            Err(Error::WrongShareCount {
+                expected: 4,
+                provided: 5
+            })
+        );
+        assert_eq!(
+            spdz.open_with_rng(&shares, &masks[..4], &key_shares, &mut rng),
+            Err(Error::InsufficientShares {
+                required: 5,
+                provided: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_parameters() {
+        let spdz = scheme();
+        assert_eq!(spdz.statistical_security(), 32);
+        assert_eq!(spdz.ring().exponent(), 64);
+
+        let base = GaloisRing::<u64>::from_bigint_ring(&spdz.ring().to_bigint_ring()).unwrap();
+        assert!(matches!(
+            Spdz2k::new(&base, 1),
+            Err(Error::InvalidRingParameters(_))
+        ));
+        let odd = GaloisRing::from_parameters(BigInt::from(3), 4, 2).unwrap();
+        assert!(matches!(
+            Spdz2k::new(&odd, 40),
+            Err(Error::InvalidRingParameters(_))
+        ));
+    }
+}
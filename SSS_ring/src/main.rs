@@ -1,655 +1,353 @@
-extern crate num_bigint;
-extern crate num_traits;
-
-use num_bigint::{BigInt, RandBigInt};
-use num_traits::{One, Zero};
-use core::num;
-use std::{arch::aarch64::int8x16_t, ops::Rem, vec};
-use rand::Rng;
-
-#[derive(Debug, Clone)]
-struct Polynomial {
-    coeffs: Vec<BigInt>,
-}
-
-// Implement PartialEq for Polynomial
-impl PartialEq for Polynomial {
-    fn eq(&self, other: &Self) -> bool {
-        if self.coeffs.len() != other.coeffs.len() {
-            return false;
-        }
-        for (a, b) in self.coeffs.iter().zip(other.coeffs.iter()) {
-            if a != b {
-                return false;
-            }
-        }
-        true
-    }
-}
-
-impl Polynomial {
-  
-    fn new(coeffs: Vec<BigInt>) -> Self {
-        Polynomial { coeffs }
-    }
-
-
-
-    fn degree(&self) -> usize {
-        if self.coeffs.is_empty(){
-            return 0
-        }
-        self.coeffs.len() - 1
-    }
-
-    fn trim(&mut self) {
-        while let Some(true) = self.coeffs.last().map(|x| x.is_zero()) {
-            self.coeffs.pop();
+//! Split files into Galois-ring Shamir shares and combine them again.
+//!
+//! ```text
+//! SSS_ring_extension split --prime P --exponent K --degree D --threshold T --parties N
+//!                          [--input FILE] [--output PREFIX]
+//! SSS_ring_extension combine SHARE_FILE... [--output FILE]
+//! SSS_ring_extension inspect SHARE_FILE
+//! ```
+//!
+//! `split` reads the input (stdin by default), encodes it as ring elements
+//! and writes one share file per party, `PREFIX.1.share` to `PREFIX.N.share`.
+//! A share file starts with the magic bytes `GRSF` and a container version
+//! byte, followed by the secret length and one encoded share per element:
+//!
+//! ```text
+//! "GRSF" | version: u8 | length: u64 BE | count: u32 BE | count x (record length: u32 BE | record)
+//! ```
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+
+use sss_ring_extension::{GaloisRing, ReconstructionPlan, ShareRecord};
+
+const USAGE: &str = "usage:
+  SSS_ring_extension split --prime P --exponent K --degree D --threshold T --parties N [--input FILE] [--output PREFIX]
+  SSS_ring_extension combine SHARE_FILE... [--output FILE]
+  SSS_ring_extension inspect SHARE_FILE";
+
+type CliResult<T> = Result<T, String>;
+
+/// Magic bytes at the start of every share file.
+const FILE_MAGIC: &[u8; 4] = b"GRSF";
+
+/// The share file container version written by `split`.
+const FILE_VERSION: u8 = 1;
+
+/// Length of the container header before the records.
+const HEADER_LEN: usize = FILE_MAGIC.len() + 1 + 8 + 4;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            ExitCode::FAILURE
         }
     }
-
-    fn trimmed_poly(&self)->Polynomial {
-        let mut divisor_poly = Polynomial::new(self.coeffs.clone());
-        while let Some(true) = divisor_poly.coeffs.last().map(|x| x.is_zero()) {
-            divisor_poly.coeffs.pop();
-        }
-        return divisor_poly
-    }
-    
-
-
-        /// Check if a polynomial is zero.
-        pub fn is_zero(&self) -> bool {
-            self.coeffs.iter().all(|c| c.is_zero())
-        }
-
-}
-
-#[derive(Debug, Clone)]
-struct GaloisRing {
-            modulus: BigInt,
-            irreducible: Polynomial
 }
-        
-
-impl GaloisRing{
-
-
-    
-    fn new(modulus: BigInt, irreducible: Polynomial) -> Self {
-        GaloisRing {modulus, irreducible}
-    }
-
-    fn add(&self, poly1: &Polynomial, poly2: &Polynomial) -> Polynomial {
-        let mut result = vec![BigInt::zero(); poly1.coeffs.len().max(poly2.coeffs.len())];
-        let zero = BigInt::zero(); // Avoid temporary value issues
-
-        for i in 0..result.len() {
-            let a = if i < poly1.coeffs.len() { &poly1.coeffs[i] } else { &zero };
-            let b = if i < poly2.coeffs.len() { &poly2.coeffs[i] } else { &zero };
-
-            result[i] = (a + b).rem(&self.modulus).clone();
-        }
-
-        Polynomial::new(result)
-    }
-
-
-    fn add_ring(&self, poly1: &Polynomial, poly2: &Polynomial) -> Polynomial {
-        let mut result = vec![BigInt::zero(); poly1.coeffs.len().max(poly2.coeffs.len())];
-        let zero = BigInt::zero(); // Avoid temporary value issues
-
-        for i in 0..result.len() {
-            let a = if i < poly1.coeffs.len() { &poly1.coeffs[i] } else { &zero };
-            let b = if i < poly2.coeffs.len() { &poly2.coeffs[i] } else { &zero };
-
-            result[i] = (a + b).rem(&self.modulus).clone();
-        }
-
-        let poly_result = Polynomial::new(result);
-        let (_,result_coeffs_ring) = self.polynomial_long_division( &poly_result, &self.irreducible);
-
-        Polynomial::new(result_coeffs_ring.coeffs)
-    }
 
-    fn sub_mod(&self, poly1: &Polynomial, poly2: &Polynomial) -> Polynomial {
-        let mut result = vec![BigInt::zero(); poly1.coeffs.len().max(poly2.coeffs.len())];
-        let zero = BigInt::zero(); // Avoid temporary value issues
-
-        for i in 0..result.len() {
-            let a = if i < poly1.coeffs.len() { &poly1.coeffs[i] } else { &zero };
-            let b = if i < poly2.coeffs.len() { &poly2.coeffs[i] } else { &zero };
-
-            result[i] = (a - b).rem(&self.modulus).clone();
-            if result[i].sign() == num_bigint::Sign::Minus {
-                result[i] += self.modulus.clone();
+fn run(args: &[String]) -> CliResult<()> {
+    let (command, rest) = args.split_first().ok_or("missing subcommand")?;
+    let (positional, options) = parse_options(rest)?;
+    match command.as_str() {
+        "split" => {
+            if !positional.is_empty() {
+                return Err(format!("unexpected argument {}", positional[0]));
             }
-        }
-
-        Polynomial::new(result)
-    }
-
-
-    fn mul(&self, poly1: &Polynomial, poly2: &Polynomial) -> Polynomial {
-        let mut result_coeffs = vec![BigInt::zero(); poly1.degree() + poly2.degree() + 1];
-
-        for i in 0..=poly1.degree() {
-            for j in 0..=poly2.degree() {
-                let mut result = result_coeffs[i + j].clone(); // Clone the current value
-                result += &poly1.coeffs[i] * &poly2.coeffs[j];
-                result_coeffs[i + j] = result.rem(&self.modulus).clone(); // Apply the modulus and store back
-            }
-        }
-
-        Polynomial::new(result_coeffs)
-    }
-    
-        fn mul_ring(&self, poly1:  &Polynomial, poly2: &Polynomial) -> Polynomial {
-            let mut result_coeffs = vec![BigInt::zero(); poly1.degree() + poly2.degree() + 1];
-    
-            for i in 0..=poly1.degree() {
-                for j in 0..=poly2.degree() {
-                    let mut result = result_coeffs[i + j].clone(); // Clone the current value
-                    result += &poly1.coeffs[i] * &poly2.coeffs[j];
-                    result_coeffs[i + j] = result.rem(&self.modulus).clone(); // Apply the modulus and store back
+            let ring = GaloisRing::from_parameters(
+                required(&options, "prime")?,
+                required(&options, "exponent")?,
+                required(&options, "degree")?,
+            )
+            .map_err(|e| e.to_string())?;
+            let parties: usize = required(&options, "parties")?;
+            let threshold = required(&options, "threshold")?;
+            let data = match options.get("input") {
+                Some(path) => fs::read(path).map_err(|e| format!("{}: {}", path, e))?,
+                None => {
+                    let mut data = Vec::new();
+                    io::stdin()
+                        .read_to_end(&mut data)
+                        .map_err(|e| e.to_string())?;
+                    data
                 }
+            };
+            let prefix = options.get("output").map_or("secret", String::as_str);
+            for (i, file) in split(&ring, &data, parties, threshold)?
+                .into_iter()
+                .enumerate()
+            {
+                let path = format!("{}.{}.share", prefix, i + 1);
+                fs::write(&path, file).map_err(|e| format!("{}: {}", path, e))?;
             }
-            let poly_result = Polynomial::new(result_coeffs);
-            let (_,result_coeffs_ring) = self.polynomial_long_division( &poly_result, &self.irreducible);
-            return Polynomial::new(result_coeffs_ring.coeffs);
+            Ok(())
         }
-
-    
-
-        fn power_in_ring(&self, poly:  &Polynomial, exponent:usize)-> Polynomial{
-            if exponent == 0 {
-                return Polynomial::new(vec![BigInt::from(1)]);
-            }
-
-            if (poly.is_zero()){
-                return GaloisRing::zero();
-            }
-            
-            
-            let mut res_power = poly.clone();
-                for _ in 1..exponent{
-                    res_power = self.mul_ring(&res_power, &poly);
+        "combine" => {
+            let files = positional
+                .iter()
+                .map(|path| fs::read(path).map_err(|e| format!("{}: {}", path, e)))
+                .collect::<CliResult<Vec<_>>>()?;
+            let data = combine(&files)?;
+            match options.get("output") {
+                Some(path) => fs::write(path, data).map_err(|e| format!("{}: {}", path, e)),
+                None => io::stdout().write_all(&data).map_err(|e| e.to_string()),
             }
-            res_power
         }
-
-
-
-
-
-
-
-
-fn zero()-> Polynomial{
-    Polynomial::new(vec![BigInt::from(0)])
-}
-
-fn polynomial_long_division(&self, dividend: &Polynomial, divisor: &Polynomial) -> (Polynomial, Polynomial) {
-   // println!("dividend: {:?}, divisor: {:?}", dividend.coeffs, divisor.coeffs);
-
-    // Ensure that the division is only attempted if the dividend's degree is >= divisor's degree
-    
-    let divisor_trimmed= divisor.trimmed_poly(); //in case the leasing coeeficient/s is 0
-   // println!("divisor {:?}, divisor trimmed {:?}", divisor.coeffs, divisor_trimmed.coeffs);
-
-
-    if dividend.degree() < divisor_trimmed.degree() {
-        return (Polynomial::new(vec![BigInt::zero()]), dividend.clone());
-    }
-
-
-    let mut quotient = Polynomial::new(vec![BigInt::zero(); dividend.degree() - divisor_trimmed.degree() + 1]);
-    let mut remainder = dividend.clone();
-    let mut i = 0;
-
-    while remainder.degree() >= divisor_trimmed.degree() && !remainder.is_zero() && i <= 5  {
-        let degree_diff = remainder.degree() - divisor_trimmed.degree();
-
-        // Leading coefficients
-        let leading_coeff_remainder = remainder.coeffs.last().unwrap().clone();
-        let leading_coeff_divisor = divisor_trimmed.coeffs.last().unwrap().clone();
-
-        // Compute the quotient coefficient: leading_coeff_remainder / leading_coeff_divisor (mod modulus)
-        //let quotient_coeff = (leading_coeff_remainder.clone() * divisor.mod_inverse(modulus).unwrap()).rem(modulus);
-       // println!("divisor: {:?}, leading coefficient divisor: {:?}", divisor, leading_coeff_divisor);
-        let quotient_coeff = (leading_coeff_remainder.clone() * self.mod_inverse(leading_coeff_divisor).unwrap()).rem(&self.modulus);
-
-        // Construct the quotient term (align the degree with degree_diff)
-        let mut quotient_term_coeffs = vec![BigInt::zero(); degree_diff + 1];
-        quotient_term_coeffs[degree_diff] = quotient_coeff.clone();
-        let quotient_term = Polynomial::new(quotient_term_coeffs);
-
-   //     println!("remainder: {:?}, Leading coeff remainder: {:?}, Leading coeff divisor: {:?}, Quotient coefficient: {:?}",
-      //           remainder.coeffs, leading_coeff_remainder, divisor.coeffs.last().unwrap(), quotient_coeff);
-
-        // Update quotient
-        quotient = self.add(&quotient,&quotient_term);
-
-        // Subtract the product of quotient term and divisor from remainder
-        let subtrahend = self.mul(&divisor,&quotient_term);
-        remainder = self.sub_mod(&remainder,&subtrahend);
-
-        remainder.trim();
-
-//        println!("Updated remainder: {:?}", remainder.coeffs);
-        i += 1;
+        "inspect" => {
+            let [path] = positional.as_slice() else {
+                return Err("inspect takes exactly one share file".into());
+            };
+            let file = fs::read(path).map_err(|e| format!("{}: {}", path, e))?;
+            print!("{}", inspect(&file)?);
+            Ok(())
+        }
+        other => Err(format!("unknown subcommand {}", other)),
     }
-
- //   println!("Final quotient: {:?}", quotient.coeffs);
- //   println!("Final remainder: {:?}", remainder.coeffs);
-
-    (quotient, remainder)
 }
 
-/// Extended Euclidean algorithm to compute the GCD and coefficients
-fn extended_euclidean(&self, a: &Polynomial, b: &Polynomial) -> (Polynomial, Polynomial, Polynomial) {
-    let mut s = Polynomial::new(vec![BigInt::zero()]);
-    let mut old_s = Polynomial::new(vec![BigInt::one()]);
-    let mut t = Polynomial::new(vec![BigInt::one()]);
-    let mut old_t = Polynomial::new(vec![BigInt::zero()]);
-    let mut r = b.clone();
-    let mut old_r = a.clone();
-    let i = 0;
-    let mut j = 0;
-   // println!("a");
-    while !r.coeffs.is_empty() {
-  //  println!("b");
-    //    let quotient = old_r.clone().rem(&r);
-   // println!("j:{:?}",j);
-   // println!("r_i: {:?}, r_i+1: {:?}", old_r.coeffs,r.coeffs);
-
-        let (quotient,remainder) = self.polynomial_long_division(&old_r,&r);
-     //   println!("quotient: {:?}, remainder: {:?}", quotient, remainder);
-
-
-        old_r = r;
-        r = remainder;
-      //  println!("after update. r_i: {:?}, r_i+1: {:?}", old_r.coeffs,r.coeffs);
-
-        let new_s = self.sub_mod(&old_s,&self.mul(&quotient,&s));
-        old_s = std::mem::replace(&mut s, new_s);
-
-        let new_t = self.sub_mod(&old_t,&self.mul(&quotient,&t));
-        old_t = std::mem::replace(&mut t, new_t);
-
-     //   println!("i:{:?}",i);
+/// Split `--name value` options from positional arguments.
+fn parse_options(args: &[String]) -> CliResult<(Vec<String>, HashMap<String, String>)> {
+    let mut positional = Vec::new();
+    let mut options = HashMap::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.strip_prefix("--") {
+            Some(name) => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("--{} needs a value", name))?;
+                options.insert(name.to_string(), value.clone());
+            }
+            None => positional.push(arg.clone()),
+        }
     }
-
-
-    (old_r, old_t, old_s)
+    Ok((positional, options))
 }
 
-fn mod_inverse(&self, divisor: BigInt) -> Option<BigInt> {
-    let mut t = BigInt::zero();
-    let mut new_t = BigInt::one();
-    let mut r = self.modulus.clone(); // Owned BigInt
-    let mut new_r = divisor.clone();  // Owned BigInt
-  
-    while !new_r.is_zero() {
-        let quotient = &r / &new_r; // Use references to avoid moving r and new_r
-
-        let temp_t = t.clone(); // Clone t to create a temporary BigInt
-        t = new_t.clone();      // Move new_t into t
-        new_t = temp_t - &quotient * &new_t;
+fn required<T: std::str::FromStr>(options: &HashMap<String, String>, name: &str) -> CliResult<T> {
+    let value = options
+        .get(name)
+        .ok_or_else(|| format!("missing --{}", name))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value {} for --{}", value, name))
+}
 
-        let temp_r = r.clone(); // Clone r to create a temporary BigInt
-        r = new_r.clone();      // Clone new_r to avoid moving it
-        new_r = temp_r - quotient * new_r; // Now this works because new_r is cloned
+/// Share `data` element by element and return the contents of each party's share file.
+fn split(
+    ring: &GaloisRing,
+    data: &[u8],
+    parties: usize,
+    threshold: usize,
+) -> CliResult<Vec<Vec<u8>>> {
+    let mut files: Vec<Vec<u8>> = (0..parties)
+        .map(|_| {
+            let mut file = FILE_MAGIC.to_vec();
+            file.push(FILE_VERSION);
+            file.extend_from_slice(&(data.len() as u64).to_be_bytes());
+            file.extend_from_slice(&0u32.to_be_bytes());
+            file
+        })
+        .collect();
+    let blocks = ring.encode_bytes(data);
+    let block_count = u32::try_from(blocks.len())
+        .map_err(|_| format!("{} blocks do not fit in a share file", blocks.len()))?;
+    let sharings = ring
+        .shamir_secret_sharing_many(&blocks, parties, threshold)
+        .map_err(|e| e.to_string())?;
+    for shares in sharings {
+        for (party_index, (share, file)) in shares.into_iter().zip(&mut files).enumerate() {
+            let (point, value) = share.into_parts();
+            let record = ShareRecord {
+                threshold,
+                party_index: party_index + 1,
+                epoch: 0,
+                point,
+                value,
+            };
+            let bytes = ring.encode_share(&record).map_err(|e| e.to_string())?;
+            let share_len = u32::try_from(bytes.len()).map_err(|_| {
+                format!("a {}-byte share does not fit in a share file", bytes.len())
+            })?;
+            file.extend_from_slice(&share_len.to_be_bytes());
+            file.extend_from_slice(&bytes);
+        }
     }
-
-    if r > BigInt::one() {
-        None
-    } else {
-        Some((t.rem(&self.modulus) + &self.modulus).rem(&self.modulus)) // Ensure result is positive and within modulus
+    for file in &mut files {
+        file[HEADER_LEN - 4..HEADER_LEN].copy_from_slice(&block_count.to_be_bytes());
     }
+    Ok(files)
 }
 
- 
-/// Find the inverse of a polynomial in the Galois ring (Z[X] / modulus)
-fn find_inverse_in_galois_ring(&self, elem: &Polynomial) -> Option<Polynomial> {
-//    println!("0");
-
-    let (g, u, _) = self.extended_euclidean(&self.irreducible, elem);
-  //  println!("1");
-    // If g is not 1, divide u by g
-    if g.coeffs != vec![BigInt::one()] {
-        let g_inv = self.mod_inverse(g.coeffs[0].clone()).unwrap(); 
-       //     println!("t = {:?}, g_inv: {:?}",u.coeffs, g_inv);
-            let inverse_coeffs: Vec<BigInt> = u.coeffs.iter().map(|coeff| (coeff *  &g_inv).rem(&self.modulus)).collect();
-            let inverse_poly = Polynomial::new(inverse_coeffs);
-            return Some(inverse_poly);
-
+/// Reconstruct the data from share files of distinct parties.
+fn combine(files: &[Vec<u8>]) -> CliResult<Vec<u8>> {
+    let parsed = files
+        .iter()
+        .map(|file| parse_share_file(file))
+        .collect::<CliResult<Vec<_>>>()?;
+    let (ring, length, first, _) = parsed.first().ok_or("no share files given")?;
+    let threshold = first.first().map_or(1, |record| record.threshold);
+    if parsed.len() < threshold {
+        return Err(format!(
+            "{} share files are required but only {} were given",
+            threshold,
+            parsed.len()
+        ));
     }
-
-    // If g is 1, just return u mod irreducible
-    Some(u)
-}
-
-
-fn generate_exceptional_set(&self) -> Vec<Polynomial> {
-    let mut exceptional_set = Vec::new();
-
-    // Loop over all non zero elements in F_2
-    let max_value = (1 << self.irreducible.degree());
-    for i in 1..max_value  {
-        let mut coeffs = Vec::new();
-        let mut value = i;
-
-        // Convert integer i to a polynomial by interpreting the bits as coefficients
-        for _ in 0..self.irreducible.degree() {
-            coeffs.push(BigInt::from(value % 2));
-            value /= 2;
+    for (other_ring, other_length, records, _) in &parsed[1..] {
+        if other_ring.encode_ring() != ring.encode_ring()
+            || other_length != length
+            || records.len() != first.len()
+            || records
+                .iter()
+                .zip(first)
+                .any(|(record, first)| record.threshold != threshold || record.epoch != first.epoch)
+        {
+            return Err("share files belong to different sharings".into());
         }
-
-        exceptional_set.push(Polynomial::new(coeffs));
-        
     }
 
-    exceptional_set
-}
-
-
-
-
-fn random_ring_element(&self)->Polynomial{
-    let d = self.irreducible.degree();
-    let coeffs: Vec<BigInt> = (0..d).map(|_| rand::thread_rng().gen_bigint_range(&BigInt::from(0),&BigInt::from(self.modulus.clone()))).collect();
-    Polynomial{coeffs}
-}
-
- 
-fn generate_random_polynomial_with_secret(&self,secret: Polynomial)->Vec<Polynomial>{
-    let mut rand_poly: Vec<Polynomial> = vec![Polynomial::new(secret.coeffs)];
-    for _ in 1..self.irreducible.degree(){
-        rand_poly.push(self.random_ring_element());
+    // Every element of a party's file shares that party's point, so one plan serves all
+    let points = parsed[..threshold]
+        .iter()
+        .map(|(_, _, records, _)| records[0].point.clone())
+        .collect();
+    let plan = ReconstructionPlan::new(ring, points).map_err(|e| e.to_string())?;
+    let blocks = (0..first.len())
+        .map(|block| {
+            let values: Vec<_> = parsed[..threshold]
+                .iter()
+                .map(|(_, _, records, _)| records[block].value.clone())
+                .collect();
+            plan.reconstruct(&values).map_err(|e| e.to_string())
+        })
+        .collect::<CliResult<Vec<_>>>()?;
+    let data = ring.decode_bytes(&blocks).map_err(|e| e.to_string())?;
+    if data.len() != *length {
+        return Err("reconstructed data does not match the recorded length".into());
     }
-    rand_poly
+    Ok(data)
 }
 
-
-fn evaluate_polynomial(&self, point: &Polynomial, rand_polynomial_ring: &Vec<Polynomial>)-> Polynomial{
-    let mut eval_poly = Polynomial::new(vec![BigInt::from(0)]);
-    for i in 0..self.irreducible.degree(){
-        eval_poly = self.add_ring(&eval_poly,  &self.mul_ring(&rand_polynomial_ring[i],&self.power_in_ring(point,i)));
-      //  println!("------ term {:?}, current mul is {:?}, power of point {:?} is {:?}", i, self.mul_ring(&rand_polynomial_ring[i],&self.power_in_ring(point,i)), point, &self.power_in_ring(point,i));
+/// Describe a share file.
+fn inspect(file: &[u8]) -> CliResult<String> {
+    let (ring, length, records, version) = parse_share_file(file)?;
+    let mut description = format!(
+        "format version: {}\nprime: {}\nexponent: {}\ndegree: {}\nirreducible: {:?}\n",
+        version,
+        ring.prime(),
+        ring.exponent(),
+        ring.degree(),
+        ring.irreducible().coeffs
+    );
+    if let Some(record) = records.first() {
+        description += &format!(
+            "threshold: {}\nparty index: {}\nepoch: {}\npoint: {:?}\n",
+            record.threshold, record.party_index, record.epoch, record.point.coeffs
+        );
     }
- //   println!("------term of point {:?} is {:?}", point, eval_poly.clone());
-  
-    eval_poly
+    description += &format!(
+        "elements: {}\nsecret length: {} bytes\n",
+        records.len(),
+        length
+    );
+    Ok(description)
 }
 
+/// The ring, secret length, records and record format version of a share file.
+type ShareFile = (GaloisRing, usize, Vec<ShareRecord>, u8);
 
-fn shamir_secret_sharing(&self, secret: Polynomial,  number_of_parties: usize, t: usize) -> Vec<(Polynomial,Polynomial)>{
-    assert!(number_of_parties <= (1 << &self.irreducible.degree()) -1);// check that  n <= 2^d
-    assert!(number_of_parties <= (1 << &self.irreducible.degree()) -1);// check that  n <= 2^d
-
-    let random_polynomial_ring =  &self.generate_random_polynomial_with_secret(secret);
-    let mut shares: Vec<(Polynomial,Polynomial)> = vec![];
-    let mut evaluated_element: Polynomial;
-    let points = self.generate_exceptional_set();
-    for i in 0..number_of_parties{
-        evaluated_element = self.evaluate_polynomial(&points[i], &random_polynomial_ring.clone());
-        shares.push((points[i].clone(),evaluated_element));
+fn parse_share_file(file: &[u8]) -> CliResult<ShareFile> {
+    let mut rest = file;
+    let mut take = |len: usize| -> CliResult<&[u8]> {
+        if rest.len() < len {
+            return Err("truncated share file".into());
+        }
+        let (head, tail) = rest.split_at(len);
+        rest = tail;
+        Ok(head)
+    };
+    if take(FILE_MAGIC.len())? != FILE_MAGIC {
+        return Err("not a share file".into());
     }
-   // println!("random polynomial ring: {:?}", random_polynomial_ring);
-
-    shares
-}
-
-
-
-fn shamir_secret_sharing_non_random(&self, poly_vec: Vec<Polynomial>,  number_of_parties: usize) -> Vec<(Polynomial,Polynomial)>{
-    assert!(number_of_parties <= (1 << &self.irreducible.degree()) -1);// check that  n <= 2^d
-    assret!(number_of_parties >= )
-    let mut shares: Vec<(Polynomial,Polynomial)> = vec![];
-    let mut evaluated_element: Polynomial;
-    let points = self.generate_exceptional_set();
-    for i in 0..number_of_parties{
-        evaluated_element = self.evaluate_polynomial(&points[i], &poly_vec.clone());
-        shares.push((points[i].clone(),evaluated_element));
+    let file_version = take(1)?[0];
+    if file_version != FILE_VERSION {
+        return Err(format!("unsupported share file version {}", file_version));
     }
-    println!("random polynomial ring: {:?}", poly_vec);
-
-    shares
-}
-
-fn reconstruct_secret(&self, shares: Vec<(Polynomial,Polynomial)>) -> Polynomial{
-    let mut res = GaloisRing::zero();
-    for  (xi, yi) in shares.clone(){
-    let mut li = Polynomial::new(vec![BigInt::from(1)]);
-        for (xj, _) in shares.clone(){
-            if xi != xj {
-                let numerator =   xj.clone();
-                let denominator = self.sub_mod(&xj,&xi);
-                let denominator_inv = self.find_inverse_in_galois_ring(&denominator);
-                let frac = self.mul_ring(&numerator,&denominator_inv.clone().unwrap());
-               //`` println!("num: {:?}, denom {:?}, denom_inv {:?}, frac = {:?}", numerator.coeffs, denominator.coeffs, denominator_inv, frac.coeffs);
-                println!("li {:?}, frac = {:?}", li.coeffs, frac.coeffs);
-
-                li  = self.mul_ring(&li,&frac);
+    let length = u64::from_be_bytes(take(8)?.try_into().unwrap()) as usize;
+    let count = u32::from_be_bytes(take(4)?.try_into().unwrap());
+
+    let mut ring = None;
+    let mut version = 0;
+    let mut records: Vec<ShareRecord> = Vec::new();
+    for _ in 0..count {
+        let len = u32::from_be_bytes(take(4)?.try_into().unwrap()) as usize;
+        let bytes = take(len)?;
+        let record = match &ring {
+            None => {
+                let (decoded, record, record_version) =
+                    GaloisRing::decode_share_and_ring(bytes).map_err(|e| e.to_string())?;
+                ring = Some(decoded);
+                version = record_version;
+                record
             }
+            Some(ring) => ring.decode_share(bytes).map_err(|e| e.to_string())?,
+        };
+        if records.first().is_some_and(|first| {
+            first.point != record.point
+                || first.threshold != record.threshold
+                || first.party_index != record.party_index
+                || first.epoch != record.epoch
+        }) {
+            return Err("share file mixes shares of different parties".into());
         }
-        res = self.add_ring(&res,&self.mul(&yi,&li));
-        println!("partial sum: {:?}, yi:{:?}, li:{:?}", res, yi,li);
+        records.push(record);
     }
- res
-}
-
-
-}
-
-fn irreducible_polynomial(degree: usize) -> Option<Polynomial> {
-    match degree {
-        1 => {
-            // Define the irreducible polynomial r(x) = x + 1
-            Some(Polynomial::new(vec![
-                BigInt::from(1), // Coefficient for x^0
-                BigInt::from(1), // Coefficient for x^1
-            ]))
-        }
-        2 => {
-            // Define the irreducible polynomial r(x) = x^2 + x + 1
-            Some(Polynomial::new(vec![
-                BigInt::from(1), // Coefficient for x^0
-                BigInt::from(1), // Coefficient for x^1
-                BigInt::from(1), // Coefficient for x^2
-            ]))
-        }
-        3 => {
-            // Define the irreducible polynomial r(x) = x^3 + x + 1
-            Some(Polynomial::new(vec![
-                BigInt::from(1), // Coefficient for x^0
-                BigInt::from(1), // Coefficient for x^1
-                BigInt::from(0), // Coefficient for x^2
-                BigInt::from(1), // Coefficient for x^3
-            ]))
-        }
-        4 => {
-            // Define the irreducible polynomial r(x) = x^4 + x + 1
-            Some(Polynomial::new(vec![
-                BigInt::from(1), // Coefficient for x^0
-                BigInt::from(1), // Coefficient for x^1
-                BigInt::from(0), // Coefficient for x^2
-                BigInt::from(0), // Coefficient for x^3
-                BigInt::from(1), // Coefficient for x^4
-            ]))
-        }
-        5 => {
-            // Define the irreducible polynomial r(x) = x^5 + x^2 + 1
-            Some(Polynomial::new(vec![
-                BigInt::from(1), // Coefficient for x^0
-                BigInt::from(0), // Coefficient for x^1
-                BigInt::from(1), // Coefficient for x^2
-                BigInt::from(0), // Coefficient for x^3
-                BigInt::from(0), // Coefficient for x^4
-                BigInt::from(1), // Coefficient for x^5
-            ]))
-        }
-        _ => None, // Return None for any other degree
+    if !rest.is_empty() {
+        return Err("trailing bytes in share file".into());
     }
+    let ring = ring.ok_or("share file holds no shares")?;
+    Ok((ring, length, records, version))
 }
 
-
-fn main() {
-    let modulus = BigInt::from(7);
-
-    let degree = 3;
-    let number_of_parties = 3;
-    //let number_of_parties = 2^(irreducible.degree() - 1);
- 
-    let irreducible = irreducible_polynomial(degree).unwrap();
-    let ring = GaloisRing::new(modulus, irreducible);
-    let secret = Polynomial::new(vec![
-        BigInt::from(6), // Coefficient for x^0
-        BigInt::from(4), // Coefficient for x^1
-       ]);
-
-    let a1 = Polynomial::new(vec![
-        BigInt::from(5), // Coefficient for x^0
-        BigInt::from(6), // Coefficient for x^1
-       ]);
-
-       let a2 = Polynomial::new(vec![
-        BigInt::from(0), // Coefficient for x^0
-        BigInt::from(5), // Coefficient for x^1
-       ]);
-    
-       let mut non_rand_poly: Vec<Polynomial> = vec![secret.clone()];
-       non_rand_poly.push(a1);
-       non_rand_poly.push(a2);
-
-       println!("NON random polynomial ring: {:?}", non_rand_poly);
-
-    
-       let shares = ring.shamir_secret_sharing_non_random(non_rand_poly, number_of_parties);
-       println!("shares: {:?}",shares);
-
-    // Define the polynomial to invert new_r(x) = x^2 + 1
- //   let divisor = Polynomial::new(vec![BigInt::from(1), BigInt::from(0), BigInt::from(1)]);
-    //let (remainder,quotient) = ring.polynomial_long_division(&ring.irreducible, &divisor);
-    /* 
-    match find_inverse_in_galois_ring(&elem, &modulus, &irreducible) {
-        Some(inverse) => println!("Inverse in Galois Ring: {:?}", inverse),
-        None => println!("No inverse exists."),
-    }
-    */
-   //let (r,s,t) = ring.extended_euclidean(&ring.irreducible, &divisor);
-//   println!("r: {:?}, t: {:?}, s:{:?}",r,s,t);
- //  let result = ring.find_inverse_in_galois_ring(&divisor);
- //  println!("{:?}", result);
-
-   let exeptional_set = ring.generate_exceptional_set();
-   println!("Exceptional set: {:?}", exeptional_set);
-   let secret = ring.random_ring_element();
-   println!("secret: {:?}", secret);
-    println!("random polynomial ring (i.e., a list of polynomials): {:?}", ring.generate_random_polynomial_with_secret(secret.clone()));
-         // Example polynomials: x^3 + 2x^2 + 3x + 4 and x^2 + 1
-         let a = Polynomial::new(vec![
-            BigInt::from(0), // Coefficient for x^0
-            BigInt::from(1), // Coefficient for x^1
-            ]);
-        let b = Polynomial::new(vec![
-            BigInt::from(6), // Coefficient for x^0
-            BigInt::from(3),  // Coefficient for x^1
-            BigInt::from(5), // Coefficient for x^2
-            BigInt::from(2), // Coefficient for x^3
-        ]);
-      //  println!("mul ring: a: {:?}, b:{:?}, a*b = {:?}", a,b, a.mul_ring(&b, &modulus, &irreducible) );
-        println!("****** a^3 = {:?}", ring.power_in_ring(&a, 2));
-        //let rand_polynomial_ring = generate_random_polynomial_with_secret(secret.clone(), &modulus, &irreducible);
-        //let evaluated_poly = evaluate_polynomial(&zero(), rand_polynomial_ring.clone(), &modulus, &irreducible);
-        //println!("evaluated polynnomial: {:?} at 0: {:?}, with secret {:?}, gives {:?}", rand_polynomial_ring, 0, secret, evaluated_poly );
-        let shares = ring.shamir_secret_sharing(secret.clone(), number_of_parties);
-        println!("shares: {:?}",shares);
-        let reconstructed_secret = ring.reconstruct_secret(shares);
-        println!("original secret: {:?}. Reconstrcuted secret: {:?}", secret,reconstructed_secret);
-
-}
-
-
-
-  
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use num_bigint::BigInt;
 
     #[test]
-    fn test_polynomial_long_division() {
-        // Define the modulus: 7
-        let modulus = BigInt::from(7);
-
-        // Define the dividend: x^3 + 2x^2 + 3x + 4
-        let dividend = Polynomial::new(vec![
-            BigInt::from(1), // Coefficient for x^0
-            BigInt::from(1), // Coefficient for x^1
-            BigInt::from(0), // Coefficient for x^2
-            BigInt::from(0), // Coefficient for x^3
-            BigInt::from(1), // Coefficient for x^2
-        ]);
-
-        // Define the divisor: x^2 + 1
-        let divisor = Polynomial::new(vec![
-            BigInt::from(1), // Coefficient for x^0
-            BigInt::from(0), // Coefficient for x^1
-            BigInt::from(1), // Coefficient for x^2
-        ]);
-
-        // Perform polynomial long division
-        let (quotient, remainder) = polynomial_long_division(&dividend, &divisor, &modulus);
-
-        // Expected quotient: x + 2
-        let expected_quotient = Polynomial::new(vec![
-            BigInt::from(6), // Coefficient for x^1
-            BigInt::from(0), // Coefficient for x^0
-            BigInt::from(1), // Coefficient for x^2
-        ]);
-
-        // Expected remainder: x + 2
-        let expected_remainder = Polynomial::new(vec![
-            BigInt::from(2), // Coefficient for x^0
-            BigInt::from(1), // Coefficient for x^1
-        ]);
-
-        assert_eq!(quotient.coeffs, expected_quotient.coeffs, "Quotient does not match expected value");
-        assert_eq!(remainder.coeffs, expected_remainder.coeffs, "Remainder does not match expected value");
-
-
-    }
- 
-
-    #[test]
-    fn test_extended_euclidean() {
-        let modulus = BigInt::from(7);
-
-        // Example polynomials: x^3 + 2x^2 + 3x + 4 and x^2 + 1
-        let a = Polynomial::new(vec![
-            BigInt::from(1), // Coefficient for x^0
-            BigInt::from(1), // Coefficient for x^1
-            BigInt::from(0), // Coefficient for x^2
-            BigInt::from(1), // Coefficient for x^3
-            BigInt::from(1), // Coefficient for x^3
-
-        ]);
-        let b = Polynomial::new(vec![
-            BigInt::from(1), // Coefficient for x^0
-            BigInt::from(0),  // Coefficient for x^1
-            BigInt::from(1), // Coefficient for x^2
-        ]);
-
-        let (gcd, s, t) = extended_euclidean(&a, &b, &modulus);
-
-        // Verify that s*a + t*b = gcd
-        let left_side = s.mul(&a, &modulus).add(&t.mul(&b, &modulus), &modulus);
-      //  assert_eq!(left_side.rem(&modulus), gcd.rem(&modulus), "s*a + t*b should equal gcd");
-
-        // Verify that gcd is indeed the greatest common divisor
-        assert!(gcd.degree() <= b.degree(), "GCD should have a degree less than or equal to the smaller polynomial");
+    fn test_split_and_combine() {
+        let ring = GaloisRing::from_parameters(BigInt::from(7), 2, 3).unwrap();
+        let data = b"attack at dawn".to_vec();
+
+        let files = split(&ring, &data, 5, 3).unwrap();
+
+        assert_eq!(combine(&files[2..]).unwrap(), data);
+        assert_eq!(
+            combine(&[files[4].clone(), files[0].clone(), files[1].clone()]).unwrap(),
+            data
+        );
+        assert!(combine(&files[..2]).is_err());
+        let description = inspect(&files[1]).unwrap();
+        assert!(description.contains("party index: 2"));
+        assert!(description.contains("format version: 2"));
+
+        let mut future = files[0].clone();
+        future[4] = FILE_VERSION + 1;
+        assert_eq!(
+            inspect(&future),
+            Err(format!(
+                "unsupported share file version {}",
+                FILE_VERSION + 1
+            ))
+        );
+        assert_eq!(
+            inspect(&files[0][FILE_MAGIC.len()..]),
+            Err("not a share file".to_string())
+        );
     }
-
 }
@@ -0,0 +1,202 @@
+use num_bigint::BigInt;
+use rand::{CryptoRng, RngCore};
+
+use crate::coefficient::Coefficient;
+use crate::error::{check_count, Error, Result};
+use crate::galois_ring::GaloisRing;
+use crate::polynomial::Polynomial;
+use crate::reconstruction::ReconstructionPlan;
+use crate::sharing::{Share, SharingPlan};
+
+/// The BGW multiplication protocol with degree reduction for threshold-`t`
+/// sharings at a fixed set of `n >= 2t - 1` points.
+///
+/// Multiplying two shares locally gives a point on a polynomial of degree
+/// `2t - 2` whose constant term is the product. Each party reshares its local
+/// product with threshold `t`, and each party then combines the sub-shares it
+/// receives with the Lagrange coefficients of all `n` points at zero, which
+/// are computed once here. The result is a fresh threshold-`t` sharing of the
+/// product, so multiplications can be chained.
+///
+/// The protocol is secure against a passive adversary corrupting fewer than
+/// `t` parties; every party must take part.
+#[derive(Debug, Clone)]
+pub struct MultiplicationPlan<C: Coefficient = BigInt> {
+    ring: GaloisRing<C>,
+    sharing: SharingPlan<C>,
+    recombination: ReconstructionPlan<C>,
+}
+
+impl<C: Coefficient> MultiplicationPlan<C> {
+    /// Plan multiplications of threshold-`t` sharings at `points`.
+    ///
+    /// Fails with [`Error::InvalidThreshold`] unless `1 <= t` and
+    /// `2t - 1 <= points.len()`, and like [`SharingPlan::new`] for invalid points.
+    pub fn new(ring: &GaloisRing<C>, points: Vec<Polynomial<C>>, t: usize) -> Result<Self> {
+        if t == 0 || 2 * t - 1 > points.len() {
+            return Err(Error::InvalidThreshold {
+                threshold: t,
+                parties: points.len(),
+            });
+        }
+        Ok(MultiplicationPlan {
+            ring: ring.clone(),
+            sharing: SharingPlan::new(ring, points.clone(), t)?,
+            recombination: ReconstructionPlan::new(ring, points)?,
+        })
+    }
+
+    /// The evaluation points, in party order.
+    pub fn points(&self) -> &[Polynomial<C>] {
+        self.sharing.points()
+    }
+
+    /// One party's first step: multiply its shares of `a` and `b` locally and
+    /// reshare the product. The `j`-th returned share goes to party `j`.
+    pub fn reshare_product(&self, a: &Share<C>, b: &Share<C>) -> Result<Vec<Share<C>>> {
+        self.reshare_product_with_rng(a, b, &mut rand::thread_rng())
+    }
+
+    /// [`MultiplicationPlan::reshare_product`] with randomness from `rng`.
+    pub fn reshare_product_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        a: &Share<C>,
+        b: &Share<C>,
+        rng: &mut R,
+    ) -> Result<Vec<Share<C>>> {
+        a.check_same_point(b, &self.ring)?;
+        if !self.points().contains(a.point()) {
+            return Err(Error::MismatchedPoint);
+        }
+        self.sharing
+            .share(self.ring.mul_ring(a.value(), b.value()), rng)
+    }
+
+    /// One party's second step: combine `received[i]`, the sub-share sent by
+    /// the party at `points()[i]`, into its share of the product.
+    pub fn recombine(&self, received: &[Polynomial<C>]) -> Result<Polynomial<C>> {
+        self.recombination.reconstruct(received)
+    }
+
+    /// Run the protocol for all parties at once, where `a[i]` and `b[i]` are
+    /// the shares held by the party at `points()[i]`.
+    ///
+    /// Fails with [`Error::InsufficientShares`] or [`Error::WrongShareCount`]
+    /// unless there is one share per party, and with
+    /// [`Error::MismatchedPoint`] if the shares are not at the planned points
+    /// in order.
+    pub fn multiply(&self, a: &[Share<C>], b: &[Share<C>]) -> Result<Vec<Share<C>>> {
+        self.multiply_with_rng(a, b, &mut rand::thread_rng())
+    }
+
+    /// [`MultiplicationPlan::multiply`] with randomness from `rng`.
+    pub fn multiply_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        a: &[Share<C>],
+        b: &[Share<C>],
+        rng: &mut R,
+    ) -> Result<Vec<Share<C>>> {
+        let n = self.points().len();
+        check_count(n, a.len())?;
+        check_count(n, b.len())?;
+        if a.iter()
+            .zip(self.points())
+            .any(|(share, point)| share.point() != point)
+        {
+            return Err(Error::MismatchedPoint);
+        }
+        let sent = a
+            .iter()
+            .zip(b)
+            .map(|(ai, bi)| self.reshare_product_with_rng(ai, bi, rng))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.points()
+            .iter()
+            .enumerate()
+            .map(|(j, point)| {
+                let received: Vec<_> = sent.iter().map(|s| s[j].value().clone()).collect();
+                Ok(Share::from_parts(point.clone(), self.recombine(&received)?))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::ring_2_64_u64;
+
+    #[test]
+    fn test_multiparty_multiplication() {
+        let wide = ring_2_64_u64();
+        let (n, t) = (5, 3);
+        let plan = MultiplicationPlan::new(&wide, wide.exceptional_points(n).unwrap(), t).unwrap();
+        let secrets: Vec<_> = (0..3).map(|_| wide.random_ring_element()).collect();
+        let shares: Vec<_> = secrets
+            .iter()
+            .map(|s| wide.shamir_secret_sharing(s.clone(), n, t).unwrap())
+            .collect();
+
+        // Every party reshares its local product; outbox[i][j] is from i to j
+        let outbox: Vec<_> = (0..n)
+            .map(|i| plan.reshare_product(&shares[0][i], &shares[1][i]).unwrap())
+            .collect();
+        let product: Vec<_> = (0..n)
+            .map(|j| {
+                let inbox: Vec<_> = outbox.iter().map(|sent| sent[j].value().clone()).collect();
+                let value = plan.recombine(&inbox).unwrap();
+                Share::new(&wide, plan.points()[j].clone(), value).unwrap()
+            })
+            .collect();
+
+        // The result has degree t - 1 again: any t shares agree
+        let expected = wide.mul_ring(&secrets[0], &secrets[1]);
+        assert_eq!(
+            wide.reconstruct_secret(product[..t].to_vec(), t),
+            Ok(expected.clone())
+        );
+        assert_eq!(
+            wide.reconstruct_secret(product[2..].to_vec(), t),
+            Ok(expected.clone())
+        );
+
+        let cubed = plan.multiply(&product, &shares[2]).unwrap();
+        assert_eq!(
+            wide.reconstruct_secret(cubed[1..4].to_vec(), t),
+            Ok(wide.mul_ring(&expected, &secrets[2]))
+        );
+    }
+
+    #[test]
+    fn test_requires_honest_majority() {
+        let ring = GaloisRing::from_parameters(BigInt::from(3), 2, 2).unwrap();
+        let points = ring.exceptional_points(4).unwrap();
+
+        assert_eq!(
+            MultiplicationPlan::new(&ring, points.clone(), 3).err(),
+            Some(Error::InvalidThreshold {
+                threshold: 3,
+                parties: 4
+            })
+        );
+        let plan = MultiplicationPlan::new(&ring, points[..3].to_vec(), 2).unwrap();
+        let shares = ring
+            .shamir_secret_sharing_at_points(ring.random_ring_element(), points[1..].to_vec(), 2)
+            .unwrap();
+        assert_eq!(
+            plan.multiply(&shares, &shares).err(),
+            Some(Error::MismatchedPoint)
+        );
+        let shares = ring
+            .shamir_secret_sharing_at_points(ring.random_ring_element(), points, 2)
+            .unwrap();
+        assert_eq!(
+            plan.multiply(&shares, &shares).err(),
+            Some(Error::WrongShareCount {
+                expected: 3,
+                provided: 4
+            })
+        );
+    }
+}
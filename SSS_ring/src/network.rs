@@ -0,0 +1,294 @@
+//! An in-process network for running protocols with one thread per party.
+//!
+//! Protocols are written from a single party's point of view against a
+//! [`Party`], which can send to and receive from any other party over
+//! private FIFO channels and draw randomness from its own seeded generator.
+//! [`simulate`] runs the parties as threads and reports their outputs
+//! together with the traffic they generated. Since every channel is FIFO and
+//! each party's generator is seeded from the simulation seed, a run's outputs
+//! and counts do not depend on thread scheduling.
+//!
+//! Rounds are counted per party: a party enters a new round when it sends
+//! after having received, or sends for the first time. The network's round
+//! count is the largest of the parties'.
+
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::thread;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::coefficient::Coefficient;
+use crate::error::{check_count, Error, Result};
+use crate::polynomial::Polynomial;
+use crate::serialization::encoded_polynomial_len;
+
+/// A value that can be sent between parties.
+pub trait Message: Clone + Send + 'static {
+    /// Number of bytes the value takes on the wire.
+    fn byte_len(&self) -> usize;
+}
+
+/// Ring elements count with their size in the binary format of
+/// [`crate::serialization`].
+impl<C: Coefficient> Message for Polynomial<C> {
+    fn byte_len(&self) -> usize {
+        encoded_polynomial_len(self)
+    }
+}
+
+impl<M: Message> Message for Vec<M> {
+    fn byte_len(&self) -> usize {
+        self.iter().map(Message::byte_len).sum()
+    }
+}
+
+impl<A: Message, B: Message> Message for (A, B) {
+    fn byte_len(&self) -> usize {
+        self.0.byte_len() + self.1.byte_len()
+    }
+}
+
+/// Traffic generated by a simulation. Messages a party sends to itself are
+/// not counted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetworkStats {
+    pub rounds: usize,
+    pub messages: usize,
+    pub bytes: usize,
+}
+
+/// The outputs of all parties, in party order, and the traffic of the run.
+#[derive(Debug, Clone)]
+pub struct Simulation<T> {
+    pub outputs: Vec<T>,
+    pub stats: NetworkStats,
+}
+
+/// One party's view of the network.
+pub struct Party<M: Message> {
+    index: usize,
+    senders: Vec<Sender<M>>,
+    receivers: Vec<Receiver<M>>,
+    rng: StdRng,
+    stats: NetworkStats,
+    sending: bool,
+}
+
+impl<M: Message> Party<M> {
+    /// This party's index, from 0 to `parties() - 1`.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Number of parties in the network.
+    pub fn parties(&self) -> usize {
+        self.senders.len()
+    }
+
+    /// This party's random number generator.
+    pub fn rng(&mut self) -> &mut StdRng {
+        &mut self.rng
+    }
+
+    /// Send `message` to party `to`.
+    ///
+    /// Fails with [`Error::PartyDisconnected`] if `to` has already finished.
+    /// Panics if `to` is not a party.
+    pub fn send(&mut self, to: usize, message: M) -> Result<()> {
+        if !self.sending {
+            self.sending = true;
+            self.stats.rounds += 1;
+        }
+        if to != self.index {
+            self.stats.messages += 1;
+            self.stats.bytes += message.byte_len();
+        }
+        self.senders[to]
+            .send(message)
+            .map_err(|_| Error::PartyDisconnected(to))
+    }
+
+    /// Send `message` to every party, this one included.
+    pub fn broadcast(&mut self, message: &M) -> Result<()> {
+        (0..self.parties()).try_for_each(|to| self.send(to, message.clone()))
+    }
+
+    /// Send `messages[j]` to party `j`, for every party.
+    ///
+    /// Fails with [`Error::InsufficientShares`] or [`Error::WrongShareCount`]
+    /// unless there is one message per party.
+    pub fn scatter(&mut self, messages: Vec<M>) -> Result<()> {
+        check_count(self.parties(), messages.len())?;
+        messages
+            .into_iter()
+            .enumerate()
+            .try_for_each(|(to, message)| self.send(to, message))
+    }
+
+    /// Wait for the next message from party `from`.
+    ///
+    /// Fails with [`Error::PartyDisconnected`] if `from` finished without
+    /// sending it. Panics if `from` is not a party.
+    pub fn receive(&mut self, from: usize) -> Result<M> {
+        self.sending = false;
+        self.receivers[from]
+            .recv()
+            .map_err(|_| Error::PartyDisconnected(from))
+    }
+
+    /// Wait for the next message from every party, in party order.
+    pub fn receive_all(&mut self) -> Result<Vec<M>> {
+        (0..self.parties()).map(|from| self.receive(from)).collect()
+    }
+}
+
+/// Run `protocol` for `n` parties, each on its own thread, with party `i`'s
+/// generator seeded from `seed` and `i`.
+///
+/// Returns the first error in party order if any party fails. Panics in a
+/// party are propagated.
+pub fn simulate<M, T, F>(n: usize, seed: u64, protocol: F) -> Result<Simulation<T>>
+where
+    M: Message,
+    T: Send,
+    F: Fn(&mut Party<M>) -> Result<T> + Sync,
+{
+    // channels[from][to] carries messages from `from` to `to`
+    let mut senders: Vec<Vec<Sender<M>>> = (0..n).map(|_| Vec::with_capacity(n)).collect();
+    let mut receivers: Vec<Vec<Receiver<M>>> = (0..n).map(|_| Vec::with_capacity(n)).collect();
+    for from_senders in senders.iter_mut() {
+        for to_receivers in receivers.iter_mut() {
+            let (sender, receiver) = channel();
+            from_senders.push(sender);
+            to_receivers.push(receiver);
+        }
+    }
+
+    let parties =
+        senders
+            .into_iter()
+            .zip(receivers)
+            .enumerate()
+            .map(|(index, (senders, receivers))| Party {
+                index,
+                senders,
+                receivers,
+                rng: StdRng::seed_from_u64(
+                    seed ^ (index as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15),
+                ),
+                stats: NetworkStats::default(),
+                sending: false,
+            });
+
+    let results: Vec<_> = thread::scope(|scope| {
+        let handles: Vec<_> = parties
+            .map(|mut party| {
+                let protocol = &protocol;
+                scope.spawn(move || protocol(&mut party).map(|output| (output, party.stats)))
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e))
+            })
+            .collect()
+    });
+
+    let mut simulation = Simulation {
+        outputs: Vec::with_capacity(n),
+        stats: NetworkStats::default(),
+    };
+    for result in results {
+        let (output, stats) = result?;
+        simulation.outputs.push(output);
+        simulation.stats.rounds = simulation.stats.rounds.max(stats.rounds);
+        simulation.stats.messages += stats.messages;
+        simulation.stats.bytes += stats.bytes;
+    }
+    Ok(simulation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::galois_ring::GaloisRing;
+    use crate::multiplication::MultiplicationPlan;
+    use crate::sharing::{Share, SharingPlan};
+    use crate::test_utils::ring_2_64_u64;
+
+    #[test]
+    fn test_bgw_multiplication_from_one_party_view() {
+        let ring = ring_2_64_u64();
+        let (n, t) = (5, 3);
+        let points = ring.exceptional_points(n).unwrap();
+        let sharing = SharingPlan::new(&ring, points.clone(), t).unwrap();
+        let multiplication = MultiplicationPlan::new(&ring, points, t).unwrap();
+
+        // Parties 0 and 1 share inputs, multiply them and open the product
+        let run = |seed| {
+            simulate(n, seed, |party: &mut Party<Polynomial<u64>>| {
+                let me = party.index();
+                if me < 2 {
+                    let input = ring.random_ring_element_with_rng(party.rng());
+                    let shares = sharing.share(input, party.rng())?;
+                    party.scatter(shares.iter().map(|s| s.value().clone()).collect())?;
+                }
+                let x = Share::new(&ring, sharing.points()[me].clone(), party.receive(0)?)?;
+                let y = Share::new(&ring, sharing.points()[me].clone(), party.receive(1)?)?;
+
+                let sent = multiplication.reshare_product_with_rng(&x, &y, party.rng())?;
+                party.scatter(sent.iter().map(|s| s.value().clone()).collect())?;
+                let product = multiplication.recombine(&party.receive_all()?)?;
+
+                party.broadcast(&product)?;
+                let opened = party.receive_all()?;
+                let shares = sharing.points()[..t]
+                    .iter()
+                    .zip(opened)
+                    .map(|(point, value)| Share::new(&ring, point.clone(), value))
+                    .collect::<Result<Vec<_>>>()?;
+                ring.reconstruct_secret(shares, t)
+            })
+            .unwrap()
+        };
+
+        let simulation = run(7);
+        assert!(simulation.outputs.windows(2).all(|w| w[0] == w[1]));
+        assert_eq!(simulation.stats.rounds, 3);
+        assert_eq!(simulation.stats.messages, 2 * (n - 1) + 2 * n * (n - 1));
+        // Ring elements of GR(2^64, 4) take about 4 * 9 bytes on the wire
+        assert!(simulation.stats.bytes < simulation.stats.messages * 40);
+
+        // The same seed gives the same run
+        let again = run(7);
+        assert_eq!(again.outputs, simulation.outputs);
+        assert_eq!(again.stats, simulation.stats);
+    }
+
+    #[test]
+    fn test_reports_disconnected_party() {
+        let result = simulate(3, 0, |party: &mut Party<Polynomial>| {
+            if party.index() == 2 {
+                return Ok(());
+            }
+            party.receive(2).map(|_| ())
+        });
+
+        assert_eq!(result.err(), Some(Error::PartyDisconnected(2)));
+
+        let result = simulate(2, 0, |party: &mut Party<Polynomial>| {
+            party.scatter(vec![GaloisRing::zero(); 3])
+        });
+        assert_eq!(
+            result.err(),
+            Some(Error::WrongShareCount {
+                expected: 2,
+                provided: 3
+            })
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+use crate::error::{Error, Result};
+
+/// Total number of Pollard rho steps [`prime_factors`] may take, enough to
+/// split off prime factors of up to about 32 bits.
+pub const FACTORING_STEP_LIMIT: u64 = 1 << 16;
+
+/// Number of Pollard rho steps between gcd computations.
+const RHO_BATCH: u64 = 64;
+
+/// Bases for the Miller–Rabin test; deterministic for `n < 3.3 * 10^24`.
+const MILLER_RABIN_BASES: [u32; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Miller–Rabin primality test with fixed bases.
+pub fn is_probable_prime(n: &BigInt) -> bool {
+    let two = BigInt::from(2);
+    if *n < two {
+        return false;
+    }
+    for base in MILLER_RABIN_BASES {
+        let base = BigInt::from(base);
+        if *n == base {
+            return true;
+        }
+        if (n % &base).is_zero() {
+            return false;
+        }
+    }
+
+    // n - 1 = 2^s * r with r odd
+    let n_minus_one: BigInt = n - 1;
+    let s = n_minus_one.trailing_zeros().unwrap_or(0);
+    let r = &n_minus_one >> s;
+
+    'witness: for base in MILLER_RABIN_BASES {
+        let mut x = BigInt::from(base).modpow(&r, n);
+        if x.is_one() || x == n_minus_one {
+            continue;
+        }
+        for _ in 1..s {
+            x = x.modpow(&two, n);
+            if x == n_minus_one {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Write `m` as `p^k` for a prime `p`, or return `None` if `m` is not a prime power.
+pub fn prime_power_decomposition(m: &BigInt) -> Option<(BigInt, u32)> {
+    if *m < BigInt::from(2) {
+        return None;
+    }
+
+    // Try the largest exponents first so that e.g. 2^64 yields (2, 64), not (2^32, 2)
+    let max_exponent = m.bits() as u32;
+    for k in (1..=max_exponent).rev() {
+        let root = m.nth_root(k);
+        if root.pow(k) == *m && is_probable_prime(&root) {
+            return Some((root, k));
+        }
+    }
+    None
+}
+
+/// Distinct prime factors of `n`, in increasing order.
+///
+/// Small factors are removed by trial division and the rest are split with
+/// Pollard's rho, so this is practical for `n` up to a few hundred bits with
+/// no two large prime factors. Fails with [`Error::FactoringLimitExceeded`]
+/// once Pollard's rho has taken [`FACTORING_STEP_LIMIT`] steps, rather than
+/// running for an unbounded time on a product of large primes.
+pub fn prime_factors(n: &BigInt) -> Result<Vec<BigInt>> {
+    let mut factors = Vec::new();
+    let mut remaining = n.clone();
+
+    for small in 2u32..1000 {
+        let small = BigInt::from(small);
+        if (&remaining % &small).is_zero() {
+            factors.push(small.clone());
+            while (&remaining % &small).is_zero() {
+                remaining /= &small;
+            }
+        }
+    }
+
+    let mut steps = FACTORING_STEP_LIMIT;
+    let mut stack = vec![remaining];
+    while let Some(m) = stack.pop() {
+        if m.is_one() {
+            continue;
+        }
+        if is_probable_prime(&m) {
+            factors.push(m);
+            continue;
+        }
+        let divisor = pollard_rho(&m, &mut steps).ok_or(Error::FactoringLimitExceeded)?;
+        stack.push(&m / &divisor);
+        stack.push(divisor);
+    }
+
+    factors.sort();
+    factors.dedup();
+    Ok(factors)
+}
+
+/// A non-trivial divisor of the composite `n`, found with Pollard's rho, or
+/// `None` if that takes more than `steps` steps. Decrements `steps` by the
+/// number of steps taken.
+///
+/// Differences are multiplied together and only their product is checked
+/// with a gcd, once per batch of [`RHO_BATCH`] steps; a batch whose product
+/// collapses to `n` is replayed one step at a time.
+fn pollard_rho(n: &BigInt, steps: &mut u64) -> Option<BigInt> {
+    let mut c = BigInt::one();
+    loop {
+        let step = |x: &BigInt| (x * x + &c) % n;
+        let mut x = BigInt::from(2);
+        let mut y = x.clone();
+        let mut d = BigInt::one();
+        while d.is_one() {
+            let (saved_x, saved_y) = (x.clone(), y.clone());
+            let mut product = BigInt::one();
+            for _ in 0..RHO_BATCH {
+                *steps = steps.checked_sub(1)?;
+                x = step(&x);
+                y = step(&step(&y));
+                product = product * (&x - &y) % n;
+            }
+            d = product.gcd(n);
+            if d == *n {
+                (x, y) = (saved_x, saved_y);
+                d = BigInt::one();
+                while d.is_one() {
+                    x = step(&x);
+                    y = step(&step(&y));
+                    d = (&x - &y).gcd(n);
+                }
+            }
+        }
+        if d != *n {
+            return Some(d);
+        }
+        c += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_probable_prime() {
+        let primes: Vec<u64> = vec![2, 3, 7, 65537, 4294967291, 18446744073709551557];
+        for p in primes {
+            assert!(is_probable_prime(&BigInt::from(p)), "{} is prime", p);
+        }
+        let composites: Vec<u64> = vec![0, 1, 4, 561, 4294967297, 3215031751];
+        for c in composites {
+            assert!(!is_probable_prime(&BigInt::from(c)), "{} is composite", c);
+        }
+    }
+
+    #[test]
+    fn test_prime_power_decomposition() {
+        let two_pow_64 = BigInt::one() << 64;
+        assert_eq!(
+            prime_power_decomposition(&two_pow_64),
+            Some((BigInt::from(2), 64))
+        );
+        assert_eq!(
+            prime_power_decomposition(&BigInt::from(27)),
+            Some((BigInt::from(3), 3))
+        );
+        assert_eq!(
+            prime_power_decomposition(&BigInt::from(7)),
+            Some((BigInt::from(7), 1))
+        );
+        assert_eq!(prime_power_decomposition(&BigInt::from(12)), None);
+    }
+
+    #[test]
+    fn test_prime_factors() {
+        let factors: Vec<BigInt> = [3u64, 5, 17, 257, 641, 65537, 6700417]
+            .iter()
+            .map(|&f| BigInt::from(f))
+            .collect();
+        // 2^64 - 1 = 3 * 5 * 17 * 257 * 641 * 65537 * 6700417
+        assert_eq!(prime_factors(&((BigInt::one() << 64) - 1)), Ok(factors));
+        assert_eq!(
+            prime_factors(&BigInt::from(1000)),
+            Ok(vec![BigInt::from(2), BigInt::from(5)])
+        );
+
+        // The product of two 61-bit primes is beyond the step limit
+        let p = (BigInt::one() << 61) - 1;
+        let q = BigInt::from(2305843009213693921u64);
+        assert!(is_probable_prime(&q));
+        assert_eq!(prime_factors(&(p * q)), Err(Error::FactoringLimitExceeded));
+    }
+}
@@ -0,0 +1,196 @@
+use num_bigint::BigInt;
+use rand::{CryptoRng, RngCore};
+
+use crate::coefficient::Coefficient;
+use crate::error::{Error, Result};
+use crate::galois_ring::GaloisRing;
+use crate::polynomial::Polynomial;
+use crate::sharing::{check_threshold, Share, SharingPlan};
+
+impl<C: Coefficient> GaloisRing<C> {
+    /// Evaluation points for packing `secret_count` secrets among
+    /// `number_of_parties` parties: the first `secret_count` digit vectors hold
+    /// the secrets and the next `number_of_parties` are the party points, in
+    /// that order.
+    ///
+    /// Fails with [`Error::TooManyParties`] if the exceptional set has fewer
+    /// than `secret_count + number_of_parties` points.
+    pub fn packed_evaluation_points(
+        &self,
+        secret_count: usize,
+        number_of_parties: usize,
+    ) -> Result<Vec<Polynomial<C>>> {
+        let available = self.exceptional_set_size() - secret_count;
+        if BigInt::from(number_of_parties) > available {
+            return Err(Error::TooManyParties {
+                requested: number_of_parties,
+                available,
+            });
+        }
+        Ok((0..secret_count + number_of_parties)
+            .map(|i| self.exceptional_point(&BigInt::from(i)))
+            .collect())
+    }
+
+    /// Franklin–Yung packed sharing of `secrets` among `number_of_parties` parties.
+    ///
+    /// The `l = secrets.len()` secrets are the values of one sharing polynomial
+    /// of degree `t + l - 2` at the secret points of
+    /// [`GaloisRing::packed_evaluation_points`], so each share carries `l`
+    /// secrets. Any `t - 1` shares reveal nothing and any `t + l - 1` shares
+    /// reconstruct all secrets with [`GaloisRing::reconstruct_packed`].
+    pub fn packed_secret_sharing(
+        &self,
+        secrets: Vec<Polynomial<C>>,
+        number_of_parties: usize,
+        t: usize,
+    ) -> Result<Vec<Share<C>>> {
+        self.packed_secret_sharing_with_rng(secrets, number_of_parties, t, &mut rand::thread_rng())
+    }
+
+    /// [`GaloisRing::packed_secret_sharing`] with randomness from `rng`.
+    pub fn packed_secret_sharing_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        secrets: Vec<Polynomial<C>>,
+        number_of_parties: usize,
+        t: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Share<C>>> {
+        if secrets.is_empty() {
+            return Err(Error::NoSecrets);
+        }
+        if t == 0 {
+            return Err(Error::InvalidThreshold {
+                threshold: t,
+                parties: number_of_parties,
+            });
+        }
+        let threshold = t + secrets.len() - 1;
+        check_threshold(threshold, number_of_parties)?;
+        for secret in &secrets {
+            self.validate_element(secret)?;
+        }
+        let mut points = self.packed_evaluation_points(secrets.len(), number_of_parties)?;
+        let party_points = points.split_off(secrets.len());
+
+        // The sharing polynomial takes the secrets at the secret points and
+        // uniformly random values at the first t - 1 party points
+        let nodes: Vec<Share<C>> = points
+            .into_iter()
+            .zip(secrets)
+            .chain(
+                party_points[..t - 1]
+                    .iter()
+                    .map(|point| (point.clone(), self.random_ring_element_with_rng(rng))),
+            )
+            .map(|(point, value)| Share::from_parts(point, value))
+            .collect();
+        let poly_vec = self.interpolate_polynomial(&nodes)?;
+        let values =
+            SharingPlan::new(self, party_points.clone(), threshold)?.evaluate(&poly_vec)?;
+        Ok(party_points
+            .into_iter()
+            .zip(values)
+            .map(|(point, value)| Share::from_parts(point, value))
+            .collect())
+    }
+
+    /// Recover the `secret_count` secrets of a packed sharing.
+    ///
+    /// Needs at least `t + secret_count - 1` shares; with fewer, the result is
+    /// not the shared secrets. Fails on the same invalid shares as
+    /// [`GaloisRing::reconstruct_secret`], and with [`Error::ReservedPoint`]
+    /// if a share sits at one of the secret points.
+    pub fn reconstruct_packed(
+        &self,
+        shares: Vec<Share<C>>,
+        secret_count: usize,
+    ) -> Result<Vec<Polynomial<C>>> {
+        if secret_count == 0 {
+            return Err(Error::NoSecrets);
+        }
+        if shares.len() < secret_count {
+            return Err(Error::InsufficientShares {
+                required: secret_count,
+                provided: shares.len(),
+            });
+        }
+        self.validate_shares(&shares)?;
+        let secret_points = self.packed_evaluation_points(secret_count, 0)?;
+        if shares
+            .iter()
+            .any(|share| secret_points.contains(share.point()))
+        {
+            return Err(Error::ReservedPoint);
+        }
+        secret_points
+            .iter()
+            .map(|point| self.interpolate_at(&shares, point))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::irreducible::irreducible_polynomial;
+    use crate::test_utils::ring_2_64_u64;
+
+    #[test]
+    fn test_packed_sharing_round_trip() {
+        let wide = ring_2_64_u64();
+        let secrets: Vec<_> = (0..3).map(|_| wide.random_ring_element()).collect();
+
+        // t = 3 and three secrets: any five shares reconstruct
+        let shares = wide.packed_secret_sharing(secrets.clone(), 10, 3).unwrap();
+
+        assert_eq!(shares.len(), 10);
+        assert_eq!(
+            wide.reconstruct_packed(shares[5..].to_vec(), 3),
+            Ok(secrets.clone())
+        );
+        assert_eq!(
+            wide.reconstruct_packed(shares[..5].to_vec(), 3),
+            Ok(secrets.clone())
+        );
+
+        // A share claiming a secret point would return its own value
+        let mut forged = shares[..5].to_vec();
+        forged[0] = Share::new(
+            &wide,
+            wide.exceptional_point(&BigInt::from(1)),
+            forged[0].value().clone(),
+        )
+        .unwrap();
+        assert_eq!(
+            wide.reconstruct_packed(forged, 3),
+            Err(Error::ReservedPoint)
+        );
+    }
+
+    #[test]
+    fn test_packed_sharing_parameters() {
+        let ring = GaloisRing::new(BigInt::from(8), irreducible_polynomial(3).unwrap()).unwrap();
+        let secrets = vec![ring.random_ring_element(), ring.random_ring_element()];
+
+        assert_eq!(
+            ring.packed_secret_sharing(vec![], 4, 2),
+            Err(Error::NoSecrets)
+        );
+        assert_eq!(
+            ring.packed_secret_sharing(secrets.clone(), 4, 4),
+            Err(Error::InvalidThreshold {
+                threshold: 5,
+                parties: 4
+            })
+        );
+        // Two of the eight points hold the secrets, leaving six for parties
+        assert_eq!(
+            ring.packed_secret_sharing(secrets, 7, 2),
+            Err(Error::TooManyParties {
+                requested: 7,
+                available: BigInt::from(6)
+            })
+        );
+    }
+}
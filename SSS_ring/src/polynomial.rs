@@ -0,0 +1,95 @@
+use num_bigint::BigInt;
+
+use crate::coefficient::Coefficient;
+
+/// A polynomial with coefficients of type `C`, stored lowest degree first.
+///
+/// Elements of a Galois ring are represented as polynomials of degree below
+/// the degree of the ring's irreducible polynomial.
+#[derive(Debug, Clone)]
+pub struct Polynomial<C = BigInt> {
+    pub coeffs: Vec<C>,
+}
+
+// Two polynomials are equal when they agree up to trailing zero coefficients.
+impl<C: Coefficient> PartialEq for Polynomial<C> {
+    fn eq(&self, other: &Self) -> bool {
+        let zero = C::zero();
+        let len = self.coeffs.len().max(other.coeffs.len());
+        (0..len).all(|i| {
+            let a = self.coeffs.get(i).unwrap_or(&zero);
+            let b = other.coeffs.get(i).unwrap_or(&zero);
+            a == b
+        })
+    }
+}
+
+impl<C: Coefficient> Eq for Polynomial<C> {}
+
+impl<C: Coefficient> Polynomial<C> {
+    pub fn new(coeffs: Vec<C>) -> Self {
+        Polynomial { coeffs }
+    }
+
+    /// The constant polynomial `c`.
+    pub fn constant(c: C) -> Self {
+        Polynomial { coeffs: vec![c] }
+    }
+
+    /// The zero polynomial.
+    pub fn zero() -> Self {
+        Polynomial::constant(C::zero())
+    }
+
+    /// The constant polynomial 1.
+    pub fn one() -> Self {
+        Polynomial::constant(C::one())
+    }
+
+    /// Degree of the polynomial as stored, i.e. `coeffs.len() - 1`.
+    pub fn degree(&self) -> usize {
+        if self.coeffs.is_empty() {
+            return 0;
+        }
+        self.coeffs.len() - 1
+    }
+
+    /// Remove trailing zero coefficients in place.
+    pub fn trim(&mut self) {
+        while let Some(true) = self.coeffs.last().map(|x| x.is_zero()) {
+            self.coeffs.pop();
+        }
+    }
+
+    /// Copy of the polynomial without trailing zero coefficients.
+    pub fn trimmed_poly(&self) -> Polynomial<C> {
+        let mut trimmed = self.clone();
+        trimmed.trim();
+        trimmed
+    }
+
+    /// Check if a polynomial is zero.
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.iter().all(|c| c.is_zero())
+    }
+
+    /// The same polynomial with [`BigInt`] coefficients.
+    pub fn to_bigint(&self) -> Polynomial {
+        Polynomial::new(self.coeffs.iter().map(C::to_bigint).collect())
+    }
+
+    /// Reduce the coefficients of a [`BigInt`] polynomial modulo `m`.
+    pub fn from_bigint(poly: &Polynomial, modulus: &C::Modulus) -> Self {
+        Polynomial::new(
+            poly.coeffs
+                .iter()
+                .map(|c| C::from_bigint(c, modulus))
+                .collect(),
+        )
+    }
+
+    /// Whether the leading stored coefficient is one.
+    pub(crate) fn is_monic(&self) -> bool {
+        self.coeffs.last().is_some_and(|c| c.is_one())
+    }
+}
@@ -0,0 +1,118 @@
+use num_bigint::{BigInt, BigUint};
+
+use crate::coefficient::Coefficient;
+use crate::error::{Error, Result};
+use crate::galois_ring::GaloisRing;
+use crate::polynomial::Polynomial;
+
+impl<C: Coefficient> GaloisRing<C> {
+    /// `base^exponent` by left-to-right square-and-multiply, using about
+    /// `log2(exponent)` squarings.
+    pub fn pow(&self, base: &Polynomial<C>, exponent: &BigUint) -> Polynomial<C> {
+        let mut result = GaloisRing::one();
+        for i in (0..exponent.bits()).rev() {
+            result = self.mul_ring(&result, &result);
+            if exponent.bit(i) {
+                result = self.mul_ring(&result, base);
+            }
+        }
+        result
+    }
+
+    /// `base^exponent` with a fixed window of `window_bits` bits.
+    ///
+    /// The powers `base^0, ..., base^(2^w - 1)` are tabulated first, after
+    /// which every window of the exponent costs `w` squarings and at most one
+    /// multiplication. A window of 4 or 5 bits suits exponents of a few
+    /// hundred bits; `window_bits` is clamped to `1..=8`.
+    pub fn pow_windowed(
+        &self,
+        base: &Polynomial<C>,
+        exponent: &BigUint,
+        window_bits: u32,
+    ) -> Polynomial<C> {
+        let window_bits = window_bits.clamp(1, 8) as u64;
+        let mut table = Vec::with_capacity(1 << window_bits);
+        table.push(GaloisRing::one());
+        for i in 1..1usize << window_bits {
+            table.push(self.mul_ring(&table[i - 1], base));
+        }
+
+        let windows = exponent.bits().div_ceil(window_bits);
+        let mut result = GaloisRing::one();
+        for window in (0..windows).rev() {
+            let mut digit = 0;
+            for bit in (0..window_bits).rev() {
+                result = self.mul_ring(&result, &result);
+                digit = digit << 1 | usize::from(exponent.bit(window * window_bits + bit));
+            }
+            if digit != 0 {
+                result = self.mul_ring(&result, &table[digit]);
+            }
+        }
+        result
+    }
+
+    /// The order `p^((k-1)d) (p^d - 1)` of the unit group: the elements that are
+    /// non-zero modulo `p`, each lifted in `p^((k-1)d)` ways.
+    pub fn unit_group_order(&self) -> BigInt {
+        let residue_size = self.prime.pow(self.degree() as u32);
+        self.prime.pow((self.exponent - 1) * self.degree() as u32) * (residue_size - 1)
+    }
+
+    /// The inverse of a unit as `elem^(|R*| - 1)`, without the extended
+    /// Euclidean algorithm.
+    ///
+    /// Fails with [`Error::NonUnit`] for non-units.
+    pub fn inverse_by_exponentiation(&self, elem: &Polynomial<C>) -> Result<Polynomial<C>> {
+        if !self.is_unit(elem) {
+            return Err(Error::NonUnit);
+        }
+        let exponent = (self.unit_group_order() - 1u32).magnitude().clone();
+        Ok(self.pow_windowed(elem, &exponent, 4))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::ring_2_64_u64;
+
+    #[test]
+    fn test_pow_matches_repeated_multiplication() {
+        let ring = GaloisRing::from_parameters(BigInt::from(5), 3, 4).unwrap();
+        let base = ring.random_ring_element();
+
+        let mut expected = GaloisRing::one();
+        for e in 0u32..40 {
+            let exponent = BigUint::from(e);
+            assert_eq!(ring.pow(&base, &exponent), expected);
+            for window_bits in [1, 3, 4] {
+                assert_eq!(ring.pow_windowed(&base, &exponent, window_bits), expected);
+            }
+            expected = ring.mul_ring(&expected, &base);
+        }
+    }
+
+    #[test]
+    fn test_big_exponents() {
+        // |GF(2^127)*| = 2^127 - 1 is far too large for a linear loop
+        let field = GaloisRing::from_parameters(BigInt::from(2), 1, 127).unwrap();
+        let elem = field.random_ring_element();
+        let order = field.unit_group_order();
+        assert_eq!(order, (BigInt::from(1) << 127) - 1);
+        assert_eq!(
+            field.pow_windowed(&elem, order.magnitude(), 5),
+            GaloisRing::one()
+        );
+
+        let wide = ring_2_64_u64();
+        let unit = Polynomial::new(vec![1, 2, 0, 1]);
+        let inverse = wide.inverse_by_exponentiation(&unit).unwrap();
+        assert_eq!(inverse, wide.find_inverse_in_galois_ring(&unit).unwrap());
+        assert_eq!(
+            wide.inverse_by_exponentiation(&Polynomial::new(vec![2, 2])),
+            Err(Error::NonUnit)
+        );
+    }
+}
@@ -0,0 +1,353 @@
+use num_bigint::BigInt;
+
+use crate::coefficient::Coefficient;
+use crate::error::{check_count, Error, Result};
+use crate::galois_ring::GaloisRing;
+use crate::polynomial::Polynomial;
+use crate::sharing::Share;
+
+impl<C: Coefficient> GaloisRing<C> {
+    /// Recover the secret of a threshold-`t` sharing by Lagrange interpolation
+    /// of the shares at zero.
+    ///
+    /// Fails with [`Error::InsufficientShares`] if fewer than `t` shares (or
+    /// none) are given, and if two shares have the same evaluation point, a
+    /// share does not belong to the ring, or a difference of evaluation points
+    /// is not a unit.
+    pub fn reconstruct_secret(&self, shares: Vec<Share<C>>, t: usize) -> Result<Polynomial<C>> {
+        if t == 0 || shares.len() < t {
+            return Err(Error::InsufficientShares {
+                required: t.max(1),
+                provided: shares.len(),
+            });
+        }
+        self.validate_shares(&shares)?;
+        self.interpolate_at(&shares, &GaloisRing::zero())
+    }
+
+    /// Check that every share belongs to the ring and no evaluation point repeats.
+    pub(crate) fn validate_shares(&self, shares: &[Share<C>]) -> Result<()> {
+        for (i, share) in shares.iter().enumerate() {
+            share.validate(self)?;
+            if shares[..i]
+                .iter()
+                .any(|other| other.point() == share.point())
+            {
+                return Err(Error::DuplicatePoint);
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluate the polynomial of degree below `shares.len()` through `shares`
+    /// at `target`.
+    ///
+    /// Fails with [`Error::DuplicatePoint`] if two points coincide and with
+    /// [`Error::NonUnit`] if a difference of evaluation points is not a unit.
+    pub fn interpolate_at(
+        &self,
+        shares: &[Share<C>],
+        target: &Polynomial<C>,
+    ) -> Result<Polynomial<C>> {
+        let (points, values): (Vec<_>, Vec<_>) =
+            shares.iter().cloned().map(Share::into_parts).unzip();
+        ReconstructionPlan::at(self, points, target)?.reconstruct(&values)
+    }
+
+    /// The coefficients, lowest degree first, of the polynomial of degree
+    /// below `shares.len()` through `shares`.
+    ///
+    /// Each Lagrange basis polynomial is `M(x) / (x - x_i)` for the master
+    /// polynomial `M = prod (x - x_j)`, scaled by `1 / M'(x_i)`; the
+    /// quotients come from synthetic division. Fails with [`Error::NonUnit`]
+    /// if two points coincide or a difference of points is not a unit.
+    pub(crate) fn interpolate_polynomial(&self, shares: &[Share<C>]) -> Result<Vec<Polynomial<C>>> {
+        let mut master = vec![GaloisRing::one()];
+        for share in shares {
+            let mut next = vec![GaloisRing::zero(); master.len() + 1];
+            for (k, c) in master.iter().enumerate() {
+                next[k + 1] = self.add_ring(&next[k + 1], c);
+                next[k] = self.sub_mod(&next[k], &self.mul_ring(c, share.point()));
+            }
+            master = next;
+        }
+
+        let denominators: Vec<_> = shares
+            .iter()
+            .enumerate()
+            .map(|(i, si)| {
+                shares
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .fold(GaloisRing::one(), |acc, (_, sj)| {
+                        self.mul_ring(&acc, &self.sub_mod(si.point(), sj.point()))
+                    })
+            })
+            .collect();
+        let inverses = self.batch_inverse(&denominators)?;
+
+        let mut coefficients = vec![GaloisRing::zero(); shares.len()];
+        for (share, inverse) in shares.iter().zip(&inverses) {
+            let weight = self.mul_ring(share.value(), inverse);
+            // Synthetic division of M by (x - x_i), from the top coefficient down
+            let mut carry = GaloisRing::zero();
+            for k in (0..shares.len()).rev() {
+                carry = self.add_ring(&master[k + 1], &self.mul_ring(&carry, share.point()));
+                coefficients[k] = self.add_ring(&coefficients[k], &self.mul_ring(&weight, &carry));
+            }
+        }
+        Ok(coefficients)
+    }
+}
+
+/// Lagrange coefficients for a fixed set of evaluation points, computed once.
+///
+/// Reconstructing from shares at these points is then a single inner product
+/// of the share values with the cached coefficients, followed by one
+/// reduction modulo `h`.
+#[derive(Debug, Clone)]
+pub struct ReconstructionPlan<C: Coefficient = BigInt> {
+    ring: GaloisRing<C>,
+    points: Vec<Polynomial<C>>,
+    coefficients: Vec<Polynomial<C>>,
+}
+
+impl<C: Coefficient> ReconstructionPlan<C> {
+    /// Plan reconstruction of the secret at zero from shares at `points`.
+    pub fn new(ring: &GaloisRing<C>, points: Vec<Polynomial<C>>) -> Result<Self> {
+        ReconstructionPlan::at(ring, points, &GaloisRing::zero())
+    }
+
+    /// Plan interpolation at `target` from shares at `points`, using the
+    /// Lagrange basis `l_i(target) = prod_{j != i} (target - x_j) / (x_i - x_j)`.
+    ///
+    /// All denominators are inverted together with one ring inversion. Fails
+    /// if `points` is empty, contains duplicates or elements of another ring,
+    /// or has a difference that is not a unit.
+    pub fn at(
+        ring: &GaloisRing<C>,
+        points: Vec<Polynomial<C>>,
+        target: &Polynomial<C>,
+    ) -> Result<Self> {
+        if points.is_empty() {
+            return Err(Error::InsufficientShares {
+                required: 1,
+                provided: 0,
+            });
+        }
+        for (i, point) in points.iter().enumerate() {
+            ring.validate_element(point)?;
+            if points[..i].contains(point) {
+                return Err(Error::DuplicatePoint);
+            }
+        }
+
+        let mut numerators = Vec::with_capacity(points.len());
+        let mut denominators = Vec::with_capacity(points.len());
+        for (i, xi) in points.iter().enumerate() {
+            let mut numerator = GaloisRing::one();
+            let mut denominator = GaloisRing::one();
+            for (j, xj) in points.iter().enumerate() {
+                if i != j {
+                    numerator = ring.mul_ring(&numerator, &ring.sub_mod(target, xj));
+                    denominator = ring.mul_ring(&denominator, &ring.sub_mod(xi, xj));
+                }
+            }
+            numerators.push(numerator);
+            denominators.push(denominator);
+        }
+        let coefficients = ring
+            .batch_inverse(&denominators)?
+            .iter()
+            .zip(&numerators)
+            .map(|(inverse, numerator)| ring.mul_ring(numerator, inverse))
+            .collect();
+
+        Ok(ReconstructionPlan {
+            ring: ring.clone(),
+            points,
+            coefficients,
+        })
+    }
+
+    /// The evaluation points, in the order values must be given.
+    pub fn points(&self) -> &[Polynomial<C>] {
+        &self.points
+    }
+
+    /// The cached Lagrange coefficients, one per point.
+    pub fn lagrange_coefficients(&self) -> &[Polynomial<C>] {
+        &self.coefficients
+    }
+
+    /// Interpolate from `values[i]`, the share value at `points()[i]`.
+    ///
+    /// Fails with [`Error::InsufficientShares`] or [`Error::WrongShareCount`]
+    /// unless there is exactly one value per point, and with
+    /// [`Error::MismatchedRing`] if a value does not belong to the ring.
+    pub fn reconstruct(&self, values: &[Polynomial<C>]) -> Result<Polynomial<C>> {
+        check_count(self.points.len(), values.len())?;
+        let mut sum = GaloisRing::zero();
+        for (value, coefficient) in values.iter().zip(&self.coefficients) {
+            self.ring.validate_element(value)?;
+            sum = self.ring.add(&sum, &self.ring.mul(value, coefficient));
+        }
+        Ok(self.ring.reduce(&sum))
+    }
+
+    /// Interpolate from shares whose points match the plan's, in order.
+    ///
+    /// Fails with [`Error::InsufficientShares`] or [`Error::WrongShareCount`]
+    /// unless there is exactly one share per point, and with
+    /// [`Error::MismatchedPoint`] if a share's point differs from the planned
+    /// one.
+    pub fn reconstruct_shares(&self, shares: &[Share<C>]) -> Result<Polynomial<C>> {
+        check_count(self.points.len(), shares.len())?;
+        if shares
+            .iter()
+            .zip(&self.points)
+            .any(|(share, point)| share.point() != point)
+        {
+            return Err(Error::MismatchedPoint);
+        }
+        let values: Vec<_> = shares.iter().map(|share| share.value().clone()).collect();
+        self.reconstruct(&values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::irreducible::irreducible_polynomial;
+    use crate::test_utils::{ring_2_64, ring_2_64_u64};
+
+    #[test]
+    fn test_reconstruct_secret() {
+        let ring = GaloisRing::new(BigInt::from(7), irreducible_polynomial(3).unwrap()).unwrap();
+        let secret = ring.random_ring_element();
+
+        let shares = ring.shamir_secret_sharing(secret.clone(), 3, 3).unwrap();
+
+        assert_eq!(ring.reconstruct_secret(shares, 3), Ok(secret));
+    }
+
+    #[test]
+    fn test_reconstruct_from_any_t_shares() {
+        let ring = GaloisRing::new(BigInt::from(7), irreducible_polynomial(3).unwrap()).unwrap();
+        let secret = ring.random_ring_element();
+
+        let shares = ring.shamir_secret_sharing(secret.clone(), 7, 5).unwrap();
+
+        assert_eq!(
+            ring.reconstruct_secret(shares[2..].to_vec(), 5),
+            Ok(secret.clone())
+        );
+        assert_eq!(ring.reconstruct_secret(shares[..5].to_vec(), 5), Ok(secret));
+
+        // Four shares leave the secret undetermined
+        assert_eq!(
+            ring.reconstruct_secret(shares[3..].to_vec(), 5),
+            Err(Error::InsufficientShares {
+                required: 5,
+                provided: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_reconstruct_over_galois_ring_mod_two_pow_64() {
+        let ring = ring_2_64();
+        let secret = ring.random_ring_element();
+
+        let shares = ring.shamir_secret_sharing(secret.clone(), 15, 6).unwrap();
+
+        assert_eq!(ring.reconstruct_secret(shares[9..].to_vec(), 6), Ok(secret));
+    }
+
+    #[test]
+    fn test_reconstruct_with_odd_residue_characteristic() {
+        // x^3 + x + 1 has no roots modulo 7, so it is irreducible there
+        let ring = GaloisRing::new(BigInt::from(343), irreducible_polynomial(3).unwrap()).unwrap();
+        let secret = ring.random_ring_element();
+
+        // More parties than the 2^3 - 1 binary points would allow
+        let shares = ring.shamir_secret_sharing(secret.clone(), 20, 4).unwrap();
+
+        assert_eq!(
+            ring.reconstruct_secret(shares[16..].to_vec(), 4),
+            Ok(secret)
+        );
+    }
+
+    #[test]
+    fn test_reconstruct_rejects_invalid_shares() {
+        let ring = GaloisRing::new(BigInt::from(8), irreducible_polynomial(3).unwrap()).unwrap();
+        let shares = ring
+            .shamir_secret_sharing(ring.random_ring_element(), 3, 2)
+            .unwrap();
+
+        assert_eq!(
+            ring.reconstruct_secret(vec![], 2),
+            Err(Error::InsufficientShares {
+                required: 2,
+                provided: 0
+            })
+        );
+        assert_eq!(
+            ring.reconstruct_secret(vec![shares[0].clone(), shares[0].clone()], 2),
+            Err(Error::DuplicatePoint)
+        );
+
+        // Points 1 and 3 differ by 2, which is not a unit modulo 8
+        let bad_point = Share::new(
+            &ring,
+            Polynomial::constant(BigInt::from(3)),
+            shares[1].value().clone(),
+        )
+        .unwrap();
+        assert_eq!(
+            ring.reconstruct_secret(vec![shares[0].clone(), bad_point], 2),
+            Err(Error::NonUnit)
+        );
+    }
+
+    #[test]
+    fn test_reconstruction_plan_reuse() {
+        let wide = ring_2_64_u64();
+        let points = wide.exceptional_points(7).unwrap();
+        let plan = ReconstructionPlan::new(&wide, points[2..6].to_vec()).unwrap();
+
+        for _ in 0..5 {
+            let secret = wide.random_ring_element();
+            let shares = wide.shamir_secret_sharing(secret.clone(), 7, 4).unwrap();
+            assert_eq!(plan.reconstruct_shares(&shares[2..6]), Ok(secret.clone()));
+            assert_eq!(
+                wide.reconstruct_secret(shares[2..6].to_vec(), 4),
+                Ok(secret)
+            );
+        }
+
+        assert_eq!(
+            plan.reconstruct(&[GaloisRing::zero()]),
+            Err(Error::InsufficientShares {
+                required: 4,
+                provided: 1
+            })
+        );
+        let shares = wide
+            .shamir_secret_sharing(wide.random_ring_element(), 7, 4)
+            .unwrap();
+        assert_eq!(
+            plan.reconstruct_shares(&shares[..4]),
+            Err(Error::MismatchedPoint)
+        );
+        assert_eq!(
+            plan.reconstruct_shares(&shares[2..7]),
+            Err(Error::WrongShareCount {
+                expected: 4,
+                provided: 5
+            })
+        );
+    }
+}
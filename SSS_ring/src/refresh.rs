@@ -0,0 +1,144 @@
+use rand::{CryptoRng, RngCore};
+
+use crate::coefficient::Coefficient;
+use crate::error::{Error, Result};
+use crate::galois_ring::GaloisRing;
+use crate::polynomial::Polynomial;
+use crate::reconstruction::ReconstructionPlan;
+use crate::serialization::ShareRecord;
+use crate::sharing::SharingPlan;
+
+impl<C: Coefficient> GaloisRing<C> {
+    /// One party's contribution to a proactive refresh: a fresh threshold-`t`
+    /// sharing of zero at the parties' `points`, whose `j`-th value is sent to
+    /// the party at `points[j]`.
+    ///
+    /// Once every party has added the contributions it received with
+    /// [`GaloisRing::apply_refresh`], the shares encode the same secret with a
+    /// new random sharing polynomial, so shares leaked in different epochs
+    /// no longer fit together.
+    pub fn refresh_contribution(
+        &self,
+        points: &[Polynomial<C>],
+        t: usize,
+    ) -> Result<Vec<Polynomial<C>>> {
+        self.refresh_contribution_with_rng(points, t, &mut rand::thread_rng())
+    }
+
+    /// [`GaloisRing::refresh_contribution`] with randomness from `rng`.
+    pub fn refresh_contribution_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        points: &[Polynomial<C>],
+        t: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Polynomial<C>>> {
+        let plan = SharingPlan::new(self, points.to_vec(), t)?;
+        let shares = plan.share(GaloisRing::zero(), rng)?;
+        Ok(shares
+            .into_iter()
+            .map(|share| share.value().clone())
+            .collect())
+    }
+
+    /// Add the refresh `contributions` received from the other parties to
+    /// `record`, moving it to the next epoch.
+    pub fn apply_refresh(
+        &self,
+        record: &ShareRecord<C>,
+        contributions: &[Polynomial<C>],
+    ) -> Result<ShareRecord<C>> {
+        self.validate_element(&record.value)?;
+        let mut value = record.value.clone();
+        for contribution in contributions {
+            self.validate_element(contribution)?;
+            value = self.add_ring(&value, contribution);
+        }
+        Ok(ShareRecord {
+            value,
+            epoch: record.epoch + 1,
+            ..record.clone()
+        })
+    }
+
+    /// Reconstruct the secret from share records of a single epoch.
+    ///
+    /// Fails with [`Error::MismatchedEpoch`] if the records come from
+    /// different epochs, and otherwise like [`GaloisRing::reconstruct_secret`].
+    pub fn reconstruct_records(&self, records: &[ShareRecord<C>]) -> Result<Polynomial<C>> {
+        if records
+            .windows(2)
+            .any(|pair| pair[0].epoch != pair[1].epoch)
+        {
+            return Err(Error::MismatchedEpoch);
+        }
+        let (points, values): (Vec<_>, Vec<_>) = records
+            .iter()
+            .map(|record| record.share().into_parts())
+            .unzip();
+        ReconstructionPlan::new(self, points)?.reconstruct(&values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::ring_2_64_u64;
+
+    #[test]
+    fn test_refresh_simulation() {
+        let wide = ring_2_64_u64();
+        let (n, t) = (5, 3);
+        let secret = wide.random_ring_element();
+        let mut records: Vec<ShareRecord<u64>> = wide
+            .shamir_secret_sharing(secret.clone(), n, t)
+            .unwrap()
+            .into_iter()
+            .enumerate()
+            .map(|(i, share)| {
+                let (point, value) = share.into_parts();
+                ShareRecord {
+                    threshold: t,
+                    party_index: i,
+                    epoch: 0,
+                    point,
+                    value,
+                }
+            })
+            .collect();
+        let points: Vec<_> = records.iter().map(|r| r.point.clone()).collect();
+        let leaked = records[0].clone();
+
+        for epoch in 1..=3 {
+            // Every party deals a sharing of zero; party j receives the j-th value of each
+            let contributions: Vec<Vec<_>> = (0..n)
+                .map(|_| wide.refresh_contribution(&points, t).unwrap())
+                .collect();
+            records = records
+                .iter()
+                .enumerate()
+                .map(|(j, record)| {
+                    let received: Vec<_> = contributions.iter().map(|c| c[j].clone()).collect();
+                    wide.apply_refresh(record, &received).unwrap()
+                })
+                .collect();
+
+            assert!(records.iter().all(|r| r.epoch == epoch));
+            assert_eq!(wide.reconstruct_records(&records[2..]), Ok(secret.clone()));
+            assert_eq!(wide.reconstruct_records(&records[..3]), Ok(secret.clone()));
+        }
+
+        assert_ne!(records[0].value, leaked.value);
+        let mixed = [leaked.clone(), records[1].clone(), records[2].clone()];
+        assert_eq!(
+            wide.reconstruct_records(&mixed),
+            Err(Error::MismatchedEpoch)
+        );
+        // Relabelling the stale share does not help either
+        let relabelled = [
+            ShareRecord { epoch: 3, ..leaked },
+            records[1].clone(),
+            records[2].clone(),
+        ];
+        assert_ne!(wide.reconstruct_records(&relabelled), Ok(secret));
+    }
+}
@@ -0,0 +1,174 @@
+use rand::{CryptoRng, RngCore};
+
+use crate::coefficient::Coefficient;
+use crate::error::{Error, Result};
+use crate::galois_ring::GaloisRing;
+use crate::polynomial::Polynomial;
+use crate::reconstruction::ReconstructionPlan;
+use crate::sharing::{Share, SharingPlan};
+
+impl<C: Coefficient> GaloisRing<C> {
+    /// An old holder's step of resharing: share its share value `value` with
+    /// threshold `new_t` among a committee of `new_parties`, at the first
+    /// `new_parties` exceptional points.
+    ///
+    /// The `j`-th returned share goes to new party `j`.
+    pub fn reshare_contribution(
+        &self,
+        value: &Polynomial<C>,
+        new_parties: usize,
+        new_t: usize,
+    ) -> Result<Vec<Share<C>>> {
+        self.reshare_contribution_with_rng(value, new_parties, new_t, &mut rand::thread_rng())
+    }
+
+    /// [`GaloisRing::reshare_contribution`] with randomness from `rng`.
+    pub fn reshare_contribution_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        value: &Polynomial<C>,
+        new_parties: usize,
+        new_t: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Share<C>>> {
+        let points = self.exceptional_points(new_parties)?;
+        SharingPlan::new(self, points, new_t)?.share(value.clone(), rng)
+    }
+
+    /// A new holder's step of resharing: combine the `sub_shares` received from
+    /// the old holders at `old_points` with their Lagrange coefficients at zero.
+    ///
+    /// Since the secret is `sum l_i y_i` and each `y_i` was reshared with
+    /// threshold `t'`, the result is a share of the secret with threshold `t'`.
+    /// At least the old threshold of old holders must take part.
+    pub fn combine_reshares(
+        &self,
+        old_points: &[Polynomial<C>],
+        sub_shares: &[Polynomial<C>],
+    ) -> Result<Polynomial<C>> {
+        ReconstructionPlan::new(self, old_points.to_vec())?.reconstruct(sub_shares)
+    }
+
+    /// Move the threshold-`old_t` sharing held as `old_shares` to a committee
+    /// of `new_parties` with threshold `new_t`, running every holder's step in
+    /// turn and never reconstructing the secret.
+    ///
+    /// Fails with [`Error::InsufficientShares`] if `old_shares` holds fewer
+    /// than `old_t` shares.
+    pub fn reshare(
+        &self,
+        old_shares: &[Share<C>],
+        old_t: usize,
+        new_parties: usize,
+        new_t: usize,
+    ) -> Result<Vec<Share<C>>> {
+        self.reshare_with_rng(
+            old_shares,
+            old_t,
+            new_parties,
+            new_t,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// [`GaloisRing::reshare`] with randomness from `rng`.
+    pub fn reshare_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        old_shares: &[Share<C>],
+        old_t: usize,
+        new_parties: usize,
+        new_t: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Share<C>>> {
+        if old_t == 0 || old_shares.len() < old_t {
+            return Err(Error::InsufficientShares {
+                required: old_t.max(1),
+                provided: old_shares.len(),
+            });
+        }
+        self.validate_shares(old_shares)?;
+        let contributions = old_shares
+            .iter()
+            .map(|share| self.reshare_contribution_with_rng(share.value(), new_parties, new_t, rng))
+            .collect::<Result<Vec<_>>>()?;
+
+        let old_points: Vec<_> = old_shares
+            .iter()
+            .map(|share| share.point().clone())
+            .collect();
+        let plan = ReconstructionPlan::new(self, old_points)?;
+        (0..new_parties)
+            .map(|j| {
+                let received: Vec<_> = contributions.iter().map(|c| c[j].value().clone()).collect();
+                let point = contributions[0][j].point().clone();
+                Ok(Share::from_parts(point, plan.reconstruct(&received)?))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::irreducible::irreducible_polynomial;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn test_reshare_to_new_committee() {
+        let ring = GaloisRing::new(BigInt::from(343), irreducible_polynomial(3).unwrap()).unwrap();
+        let secret = ring.random_ring_element();
+        let old = ring.shamir_secret_sharing(secret.clone(), 5, 3).unwrap();
+
+        // (3, 5) to (4, 7), using old holders 2, 3 and 4 only
+        let new = ring.reshare(&old[2..], 3, 7, 4).unwrap();
+        assert_eq!(new.len(), 7);
+        assert_eq!(
+            ring.reconstruct_secret(new[3..].to_vec(), 4),
+            Ok(secret.clone())
+        );
+        assert_eq!(
+            ring.reconstruct_secret(new[..4].to_vec(), 4),
+            Ok(secret.clone())
+        );
+
+        // and down to (2, 3)
+        let smaller = ring.reshare(&new[1..5], 4, 3, 2).unwrap();
+        assert_eq!(
+            ring.reconstruct_secret(smaller[1..].to_vec(), 2),
+            Ok(secret)
+        );
+
+        // Three of the four holders would reshare a different polynomial
+        assert_eq!(
+            ring.reshare(&new[..3], 4, 3, 2),
+            Err(Error::InsufficientShares {
+                required: 4,
+                provided: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_reshare_by_hand() {
+        let ring =
+            GaloisRing::new(BigInt::from(1) << 32, irreducible_polynomial(3).unwrap()).unwrap();
+        let narrow = GaloisRing::<u32>::from_bigint_ring(&ring).unwrap();
+        let secret = narrow.random_ring_element();
+        let old = narrow.shamir_secret_sharing(secret.clone(), 4, 2).unwrap();
+
+        // Old holders 0 and 3 each send one sub-share to every new holder
+        let sent: Vec<_> = [&old[0], &old[3]]
+            .iter()
+            .map(|share| narrow.reshare_contribution(share.value(), 5, 3).unwrap())
+            .collect();
+        let old_points = [old[0].point().clone(), old[3].point().clone()];
+        let new: Vec<_> = (0..5)
+            .map(|j| {
+                let received = [sent[0][j].value().clone(), sent[1][j].value().clone()];
+                let value = narrow.combine_reshares(&old_points, &received).unwrap();
+                Share::new(&narrow, sent[0][j].point().clone(), value).unwrap()
+            })
+            .collect();
+
+        assert_eq!(narrow.reconstruct_secret(new[2..].to_vec(), 3), Ok(secret));
+    }
+}
@@ -0,0 +1,618 @@
+//! Reverse multiplication-friendly embeddings (RMFEs) of `(Z/p^k)^m` into
+//! `GR(p^k, d)`.
+//!
+//! An `(m, d)`-RMFE is a pair of `Z/p^k`-linear maps
+//! `phi: (Z/p^k)^m -> GR(p^k, d)` and `psi: GR(p^k, d) -> (Z/p^k)^m` with
+//! `psi(phi(x) * phi(y)) = x * y` coordinate-wise, so that one ring
+//! multiplication performs `m` multiplications over `Z/p^k`.
+//!
+//! The building block is polynomial interpolation over a subring
+//! `S = GR(p^k, e)` of `GR(p^k, d)`, for `e` dividing `d`, over which
+//! `1, X, ..., X^(d/e - 1)` is a basis. With evaluation points
+//! `a_1, ..., a_n` from the exceptional set of `S`, an element of `S^n` is
+//! encoded as the polynomial `f` over `S` of degree below `n` with
+//! `f(a_i) = u_i`, evaluated at `X`. For `d / e >= 2n - 1` a product of two
+//! encodings is the product polynomial, so decoding reads off its
+//! coefficients over `S` and evaluates at the points again. A last
+//! coordinate at infinity, carried by the leading coefficient, gives
+//! `n <= p^e + 1`.
+//!
+//! With `e = 1` this embeds `(Z/p^k)^n` directly, but only for `n <= p + 1`,
+//! i.e. three coordinates over `Z/2^k`. Larger batches concatenate two
+//! levels: an interpolation RMFE of `(Z/p^k)^b` into `S` encodes blocks of
+//! `b` values, and the interpolation over `S` embeds `n` blocks, for
+//! `m = b * n`. Over `Z/2^k` with `e = 3`, for example, `2n` values fit into
+//! a ring of degree `3(2n - 1)`.
+//!
+//! Since `phi(psi(z))` is linear in `z`, a shared product can be re-encoded
+//! into the image of `phi` with a preprocessed pair of sharings of a random
+//! `r` and of `phi(psi(r))`, at the cost of one opening.
+
+use std::iter;
+
+use num_bigint::BigInt;
+use num_traits::{One, ToPrimitive, Zero};
+use rand::{CryptoRng, RngCore};
+
+use crate::coefficient::Coefficient;
+use crate::error::{check_count, Error, Result};
+use crate::galois_ring::GaloisRing;
+use crate::polynomial::Polynomial;
+use crate::reconstruction::ReconstructionPlan;
+use crate::sharing::{Share, SharingPlan};
+
+/// An interpolation-based RMFE of `(Z/p^k)^m` into a Galois ring,
+/// concatenated with one into a subring for batches beyond `p + 1`.
+#[derive(Debug, Clone)]
+pub struct Rmfe<C: Coefficient = BigInt> {
+    ring: GaloisRing<C>,
+    batch_size: usize,
+    /// The subring `S = GR(p^k, e)`, as a ring of its own.
+    subring: GaloisRing<C>,
+    /// The images in `ring` of `1, b, ..., b^(e-1)` for the generator `b` of
+    /// `S`.
+    embedding: Vec<Polynomial<C>>,
+    /// Inverse of the matrix whose columns are the `Z/p^k`-basis
+    /// `b^a X^j` of `ring`; row `j * e + a` is the coordinate of `b^a X^j`.
+    coordinates: Vec<Vec<C>>,
+    /// The finite evaluation points, as elements of `S`.
+    points: Vec<Polynomial<C>>,
+    /// Lagrange coefficients of the embedded finite points at `X`, i.e. the
+    /// Lagrange basis polynomials as ring elements.
+    basis: ReconstructionPlan<C>,
+    /// `prod (X - a_i)` if the last coordinate is at infinity.
+    vanishing: Option<Polynomial<C>>,
+    /// The RMFE encoding each block into `S`, or `None` if `S = Z/p^k`.
+    inner: Option<Box<Rmfe<C>>>,
+}
+
+impl<C: Coefficient> Rmfe<C> {
+    /// An RMFE of `(Z/p^k)^m` into `ring = GR(p^k, d)`.
+    ///
+    /// Interpolates directly over `Z/p^k` if `m <= p + 1` and `d >= 2m - 1`,
+    /// and otherwise concatenates over the smallest subring that holds `m`
+    /// coordinates. Fails with [`Error::InvalidRingParameters`] if there is
+    /// none, and with [`Error::ExceptionalSetTooLarge`] if the exceptional
+    /// set of the subring, from which the points are taken, cannot be listed.
+    pub fn new(ring: &GaloisRing<C>, m: usize) -> Result<Self> {
+        let d = ring.degree();
+        let prime = ring.prime();
+        // Blocks of the inner RMFE into GR(p^k, e), which has one coordinate for e = 1
+        let (e, block) = (1..=d)
+            .filter(|e| d.is_multiple_of(*e))
+            .map(|e| (e, interpolation_capacity(prime, 1, e)))
+            .find(|&(e, block)| m > 0 && block * interpolation_capacity(prime, e, d / e) >= m)
+            .ok_or_else(|| {
+                Error::InvalidRingParameters(format!(
+                    "no RMFE of {} coordinates into a ring of degree {} over Z/{}^k",
+                    m, d, prime
+                ))
+            })?;
+        let (subring, embedding) = subring(ring, e)?;
+        let inner = match e {
+            1 => None,
+            _ => Some(Box::new(Rmfe::new(&subring, block)?)),
+        };
+        let coordinates = invert_matrix(
+            ring,
+            (0..d / e)
+                .flat_map(|j| {
+                    embedding.iter().map(move |power| {
+                        let mut monomial = vec![C::zero(); j + 1];
+                        monomial[j] = C::one();
+                        (power, Polynomial::new(monomial))
+                    })
+                })
+                .map(|(power, monomial)| ring.mul_ring(power, &monomial))
+                .collect(),
+        )?;
+
+        let count = m.div_ceil(block);
+        let finite = if BigInt::from(count) > subring.exceptional_set_size() {
+            count - 1
+        } else {
+            count
+        };
+        // The non-zero points of the exceptional set are units, so zero can join them
+        let points: Vec<_> = iter::once(GaloisRing::zero())
+            .chain(subring.generate_exceptional_set()?)
+            .take(finite)
+            .collect();
+        let embedded: Vec<_> = points
+            .iter()
+            .map(|point| embed(ring, &embedding, point))
+            .collect();
+        let x = Polynomial::new(vec![C::zero(), C::one()]);
+        let basis = ReconstructionPlan::at(ring, embedded.clone(), &x)?;
+        let vanishing = (finite < count).then(|| {
+            embedded.iter().fold(GaloisRing::one(), |product, a| {
+                ring.mul_ring(&product, &ring.sub_mod(&x, a))
+            })
+        });
+        Ok(Rmfe {
+            ring: ring.clone(),
+            batch_size: m,
+            subring,
+            embedding,
+            coordinates,
+            points,
+            basis,
+            vanishing,
+            inner,
+        })
+    }
+
+    /// The number `m` of coordinates.
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// The ring `GR(p^k, d)` embedded into.
+    pub fn ring(&self) -> &GaloisRing<C> {
+        &self.ring
+    }
+
+    /// The subring `GR(p^k, e)` the outer interpolation runs over; of degree
+    /// one unless the RMFE is concatenated.
+    pub fn subring(&self) -> &GaloisRing<C> {
+        &self.subring
+    }
+
+    /// `phi(values)`.
+    ///
+    /// Fails with [`Error::InsufficientShares`] or [`Error::WrongShareCount`]
+    /// unless there are exactly `m` values, and with [`Error::MismatchedRing`] if one is not reduced
+    /// modulo `p^k`.
+    pub fn encode(&self, values: &[C]) -> Result<Polynomial<C>> {
+        check_count(self.batch_size, values.len())?;
+        if values
+            .iter()
+            .any(|value| !value.is_reduced(self.ring.coefficient_modulus()))
+        {
+            return Err(Error::MismatchedRing);
+        }
+        let mut padded = values.to_vec();
+        padded.resize(self.block_size() * self.coordinate_count(), C::zero());
+        let coordinates: Vec<_> = padded
+            .chunks(self.block_size())
+            .map(|block| {
+                let element = match &self.inner {
+                    Some(inner) => inner.encode(block)?,
+                    None => Polynomial::constant(block[0].clone()),
+                };
+                Ok(embed(&self.ring, &self.embedding, &element))
+            })
+            .collect::<Result<_>>()?;
+        let (finite, infinite) = coordinates.split_at(self.points.len());
+        let mut encoded = self.basis.reconstruct(finite)?;
+        if let (Some(vanishing), Some(top)) = (&self.vanishing, infinite.first()) {
+            encoded = self
+                .ring
+                .add_ring(&encoded, &self.ring.mul_ring(top, vanishing));
+        }
+        Ok(encoded)
+    }
+
+    /// `psi(elem)`: the values at the points of `elem` read as a polynomial in
+    /// `X` over the subring, where the value at infinity is the coefficient
+    /// of `X^(2n-2)` for `n` blocks.
+    pub fn decode_product(&self, elem: &Polynomial<C>) -> Result<Vec<C>> {
+        let blocks = self.decode_with_top(elem, 2 * self.coordinate_count() - 2)?;
+        self.flatten(&blocks, Rmfe::decode_product)
+    }
+
+    /// The inverse of `phi` on its image, where the value at infinity is the
+    /// coefficient of `X^(n-1)`.
+    pub fn decode(&self, elem: &Polynomial<C>) -> Result<Vec<C>> {
+        let blocks = self.decode_with_top(elem, self.coordinate_count() - 1)?;
+        self.flatten(&blocks, Rmfe::decode)
+    }
+
+    /// `phi(psi(elem))`, which maps a product of encodings to the encoding of
+    /// the coordinate-wise product.
+    pub fn reencode(&self, elem: &Polynomial<C>) -> Result<Polynomial<C>> {
+        self.encode(&self.decode_product(elem)?)
+    }
+
+    /// The number of values encoded into each element of the subring.
+    fn block_size(&self) -> usize {
+        self.inner.as_ref().map_or(1, |inner| inner.batch_size)
+    }
+
+    /// The number of subring coordinates of the outer interpolation.
+    fn coordinate_count(&self) -> usize {
+        self.points.len() + usize::from(self.vanishing.is_some())
+    }
+
+    /// The subring values of `elem` at the points and at infinity.
+    fn decode_with_top(&self, elem: &Polynomial<C>, top: usize) -> Result<Vec<Polynomial<C>>> {
+        self.ring.validate_element(elem)?;
+        let modulus = self.ring.coefficient_modulus();
+        let flat: Vec<C> = self
+            .coordinates
+            .iter()
+            .map(|row| {
+                row.iter().zip(&elem.coeffs).fold(C::zero(), |acc, (a, b)| {
+                    acc.add_mod(&a.mul_mod(b, modulus), modulus)
+                })
+            })
+            .collect();
+        // The coefficients of `elem` as a polynomial in X over the subring
+        let coeffs: Vec<_> = flat
+            .chunks(self.embedding.len())
+            .map(|chunk| Polynomial::new(chunk.to_vec()).trimmed_poly())
+            .collect();
+        let mut values: Vec<_> = self
+            .points
+            .iter()
+            .map(|point| {
+                coeffs.iter().rev().fold(GaloisRing::zero(), |acc, coeff| {
+                    self.subring
+                        .add_ring(&self.subring.mul_ring(&acc, point), coeff)
+                })
+            })
+            .collect();
+        if self.vanishing.is_some() {
+            values.push(coeffs.get(top).cloned().unwrap_or_else(GaloisRing::zero));
+        }
+        Ok(values)
+    }
+
+    /// The values of the subring elements `blocks`, decoded with `decode`
+    /// by the inner RMFE, without the padding.
+    fn flatten(
+        &self,
+        blocks: &[Polynomial<C>],
+        decode: fn(&Rmfe<C>, &Polynomial<C>) -> Result<Vec<C>>,
+    ) -> Result<Vec<C>> {
+        let mut values = Vec::with_capacity(blocks.len() * self.block_size());
+        for block in blocks {
+            match &self.inner {
+                Some(inner) => values.extend(decode(inner, block)?),
+                None => values.push(block.coeffs.first().cloned().unwrap_or_else(C::zero)),
+            }
+        }
+        values.truncate(self.batch_size);
+        Ok(values)
+    }
+
+    /// The dealer's preprocessing for [`Rmfe::reencode_shares`]: `count`
+    /// pairs of threshold-`t` sharings of a random `r` and of
+    /// `phi(psi(r))`, among `n` parties at the first `n` exceptional points.
+    ///
+    /// Entry `[i][j]` is party `i`'s share of the `j`-th pair.
+    pub fn deal_reencoding_pairs(
+        &self,
+        count: usize,
+        n: usize,
+        t: usize,
+    ) -> Result<Vec<Vec<ReencodingPair<C>>>> {
+        self.deal_reencoding_pairs_with_rng(count, n, t, &mut rand::thread_rng())
+    }
+
+    /// [`Rmfe::deal_reencoding_pairs`] with randomness from `rng`.
+    pub fn deal_reencoding_pairs_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        count: usize,
+        n: usize,
+        t: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Vec<ReencodingPair<C>>>> {
+        let plan = SharingPlan::new(&self.ring, self.ring.exceptional_points(n)?, t)?;
+        let mut dealt = vec![Vec::with_capacity(count); n];
+        for _ in 0..count {
+            let mask = self.ring.random_ring_element_with_rng(rng);
+            let encoded = self.reencode(&mask)?;
+            let shares = plan.share_many(&[mask, encoded], rng)?;
+            for (i, party) in dealt.iter_mut().enumerate() {
+                party.push(ReencodingPair {
+                    mask: shares[0][i].clone(),
+                    encoded: shares[1][i].clone(),
+                });
+            }
+        }
+        Ok(dealt)
+    }
+
+    /// One party's share of `phi(psi(z))` from its share of `z`, its share
+    /// of a fresh re-encoding pair and the opened `w = z + r`.
+    pub fn reencode_share(
+        &self,
+        pair: &ReencodingPair<C>,
+        opened: &Polynomial<C>,
+    ) -> Result<Share<C>> {
+        let minus_one = self.ring.sub_mod(&GaloisRing::zero(), &GaloisRing::one());
+        let terms = [(minus_one, pair.encoded.clone())];
+        Share::linear_combination(&self.ring, &terms, &self.reencode(opened)?)
+    }
+
+    /// Re-encode a sharing of `z` into a sharing of `phi(psi(z))` for all
+    /// parties at once, where `z[i]` and `pairs[i]` are held by party `i`.
+    ///
+    /// Each party masks its share with `r`, the parties open `w = z + r`,
+    /// and `phi(psi(w)) - phi(psi(r))` is a sharing of `phi(psi(z))`. Every
+    /// pair must be used only once. Fails with [`Error::InsufficientShares`]
+    /// or [`Error::WrongShareCount`] unless there is one pair per share.
+    pub fn reencode_shares(
+        &self,
+        z: &[Share<C>],
+        pairs: &[ReencodingPair<C>],
+    ) -> Result<Vec<Share<C>>> {
+        check_count(z.len(), pairs.len())?;
+        let masked = z
+            .iter()
+            .zip(pairs)
+            .map(|(share, pair)| share.add(&pair.mask, &self.ring))
+            .collect::<Result<Vec<_>>>()?;
+        // Every party's masked share takes part in the opening
+        let opened = self.ring.reconstruct_secret(masked, z.len())?;
+        pairs
+            .iter()
+            .map(|pair| self.reencode_share(pair, &opened))
+            .collect()
+    }
+}
+
+/// One party's shares of a random `r` and of its re-encoding `phi(psi(r))`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReencodingPair<C: Coefficient = BigInt> {
+    pub mask: Share<C>,
+    pub encoded: Share<C>,
+}
+
+/// The most coordinates interpolation over `GR(p^k, e)` embeds into an
+/// extension of degree `n` over it: `p^e` finite points and infinity, with
+/// products of degree below `n`.
+fn interpolation_capacity(prime: &BigInt, e: usize, n: usize) -> usize {
+    let points = prime.pow(e as u32) + 1u32;
+    points.to_usize().unwrap_or(usize::MAX).min(n.div_ceil(2))
+}
+
+/// How many units [`subring`] tries before giving up on a generator.
+const SUBRING_CANDIDATES: u32 = 64;
+
+/// The subring `GR(p^k, e)` of `ring`, for `e` dividing its degree, and the
+/// images in `ring` of the powers `1, b, ..., b^(e-1)` of its generator.
+///
+/// The generator is a Teichmuller element `b` of degree `e`: a power of a
+/// unit that lies in the cyclic group of order `p^e - 1` and differs from
+/// its conjugates `b^(p^i)`, `0 < i < e`. Its minimal polynomial
+/// `prod (Y - b^(p^i))` has coefficients in `Z/p^k` and defines the subring.
+///
+/// Candidate units are the first [`SUBRING_CANDIDATES`] non-zero digit
+/// vectors. Each is tested in the residue field, where the power into
+/// `GF(p^e)` is cheap, and only the first that has degree `e` there is lifted.
+/// Fails with [`Error::InvalidRingParameters`] if none does.
+fn subring<C: Coefficient>(
+    ring: &GaloisRing<C>,
+    e: usize,
+) -> Result<(GaloisRing<C>, Vec<Polynomial<C>>)> {
+    let prime = ring.prime();
+    if e == 1 {
+        let linear = Polynomial::new(vec![BigInt::zero(), BigInt::one()]);
+        let base = GaloisRing::from_prime_power(prime.clone(), ring.exponent(), linear)?;
+        return Ok((
+            GaloisRing::from_bigint_ring(&base)?,
+            vec![GaloisRing::one()],
+        ));
+    }
+    let q = prime.magnitude();
+    let d = ring.degree();
+    let field = ring.residue_field();
+    // Maps the units of GF(p^d) onto those of GF(p^e)
+    let norm = (q.pow(d as u32) - 1u32) / (q.pow(e as u32) - 1u32);
+    let has_degree_e = |field: &GaloisRing, b: &Polynomial| {
+        iter::successors(Some(field.pow(b, q)), |c| Some(field.pow(c, q)))
+            .take(e - 1)
+            .all(|c| &c != b)
+    };
+    let candidate = (1..=SUBRING_CANDIDATES)
+        .map(BigInt::from)
+        .take_while(|index| index < &ring.exceptional_set_size())
+        .find(|index| has_degree_e(&field, &field.pow(&field.exceptional_point(index), &norm)))
+        .ok_or_else(|| {
+            Error::InvalidRingParameters(format!(
+                "no generator of a subring of degree {} among {} candidates",
+                e, SUBRING_CANDIDATES
+            ))
+        })?;
+
+    // The extra power p^(d(k-1)) kills the units congruent to 1 modulo p
+    let exponent = q.pow(d as u32 * (ring.exponent() - 1)) * norm;
+    let generator = ring.pow(&ring.exceptional_point(&candidate), &exponent);
+    let mut minimal = vec![GaloisRing::one()];
+    for c in iter::successors(Some(generator.clone()), |c| Some(ring.pow(c, q))).take(e) {
+        let mut next = vec![GaloisRing::zero(); minimal.len() + 1];
+        for (i, coeff) in minimal.iter().enumerate() {
+            next[i + 1] = ring.add_ring(&next[i + 1], coeff);
+            next[i] = ring.sub_mod(&next[i], &ring.mul_ring(coeff, &c));
+        }
+        minimal = next;
+    }
+    // Fixed by the Frobenius, so every coefficient is a constant
+    let irreducible = Polynomial::new(
+        minimal
+            .iter()
+            .map(|coeff| coeff.coeffs.first().map_or_else(BigInt::zero, C::to_bigint))
+            .collect(),
+    );
+    let base = GaloisRing::from_prime_power(prime.clone(), ring.exponent(), irreducible)?;
+    let powers = iter::successors(Some(GaloisRing::one()), |power| {
+        Some(ring.mul_ring(power, &generator))
+    })
+    .take(e)
+    .collect();
+    Ok((GaloisRing::from_bigint_ring(&base)?, powers))
+}
+
+/// The image in `ring` of the subring element `elem`.
+fn embed<C: Coefficient>(
+    ring: &GaloisRing<C>,
+    embedding: &[Polynomial<C>],
+    elem: &Polynomial<C>,
+) -> Polynomial<C> {
+    let sum = elem
+        .coeffs
+        .iter()
+        .zip(embedding)
+        .fold(GaloisRing::zero(), |acc, (coeff, power)| {
+            ring.add(&acc, &ring.mul(&Polynomial::constant(coeff.clone()), power))
+        });
+    ring.reduce(&sum)
+}
+
+/// The inverse over `Z/p^k` of the matrix with the coefficient vectors of
+/// `columns` as columns, by Gauss-Jordan elimination on unit pivots.
+///
+/// Fails with [`Error::NonUnit`] if the matrix is singular modulo `p`.
+fn invert_matrix<C: Coefficient>(
+    ring: &GaloisRing<C>,
+    columns: Vec<Polynomial<C>>,
+) -> Result<Vec<Vec<C>>> {
+    let n = columns.len();
+    let modulus = ring.coefficient_modulus();
+    let mut matrix: Vec<Vec<C>> = (0..n)
+        .map(|i| {
+            columns
+                .iter()
+                .map(|column| column.coeffs.get(i).cloned().unwrap_or_else(C::zero))
+                .collect()
+        })
+        .collect();
+    let mut inverse: Vec<Vec<C>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| if i == j { C::one() } else { C::zero() })
+                .collect()
+        })
+        .collect();
+    for col in 0..n {
+        let pivot = (col..n)
+            .find(|&row| !(matrix[row][col].to_bigint() % ring.prime()).is_zero())
+            .ok_or(Error::NonUnit)?;
+        matrix.swap(col, pivot);
+        inverse.swap(col, pivot);
+        let scale = C::from_bigint(&ring.mod_inverse(matrix[col][col].to_bigint())?, modulus);
+        for entry in matrix[col].iter_mut().chain(inverse[col].iter_mut()) {
+            *entry = entry.mul_mod(&scale, modulus);
+        }
+        let (pivot_row, pivot_inverse) = (matrix[col].clone(), inverse[col].clone());
+        for row in (0..n).filter(|&row| row != col) {
+            let factor = matrix[row][col].clone();
+            if factor.is_zero() {
+                continue;
+            }
+            for (entry, p) in matrix[row].iter_mut().zip(&pivot_row) {
+                *entry = entry.sub_mod(&factor.mul_mod(p, modulus), modulus);
+            }
+            for (entry, p) in inverse[row].iter_mut().zip(&pivot_inverse) {
+                *entry = entry.sub_mod(&factor.mul_mod(p, modulus), modulus);
+            }
+        }
+    }
+    Ok(inverse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::irreducible::irreducible_polynomial;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_embedding_multiplies_coordinate_wise() {
+        let ring =
+            GaloisRing::new(BigInt::from(1) << 64, irreducible_polynomial(15).unwrap()).unwrap();
+        let wide = GaloisRing::<u64>::from_bigint_ring(&ring).unwrap();
+        let mut rng = StdRng::seed_from_u64(5);
+
+        // Three coordinates interpolate over Z/2^64, five and six concatenate over GR(2^64, 3)
+        for (m, e) in [(3, 1), (5, 3), (6, 3)] {
+            let rmfe = Rmfe::new(&wide, m).unwrap();
+            assert_eq!(rmfe.subring().degree(), e);
+            let x: Vec<u64> = (0..m).map(|_| rng.gen()).collect();
+            let y: Vec<u64> = (0..m).map(|_| rng.gen()).collect();
+
+            let product = wide.mul_ring(&rmfe.encode(&x).unwrap(), &rmfe.encode(&y).unwrap());
+            let expected: Vec<_> = x.iter().zip(&y).map(|(a, b)| a.wrapping_mul(*b)).collect();
+            assert_eq!(rmfe.decode_product(&product), Ok(expected.clone()));
+            assert_eq!(rmfe.decode(&rmfe.reencode(&product).unwrap()), Ok(expected));
+            assert_eq!(rmfe.decode(&rmfe.encode(&x).unwrap()), Ok(x));
+        }
+    }
+
+    #[test]
+    fn test_rejects_batches_beyond_capacity() {
+        let ring =
+            GaloisRing::new(BigInt::from(1) << 64, irreducible_polynomial(15).unwrap()).unwrap();
+        assert!(matches!(
+            Rmfe::new(&ring, 7),
+            Err(Error::InvalidRingParameters(_))
+        ));
+        assert!(matches!(
+            Rmfe::new(&ring, 0),
+            Err(Error::InvalidRingParameters(_))
+        ));
+        let rmfe = Rmfe::new(&ring, 3).unwrap();
+        assert_eq!(
+            rmfe.encode(&vec![BigInt::from(1); 4]),
+            Err(Error::WrongShareCount {
+                expected: 3,
+                provided: 4
+            })
+        );
+        let prime_degree =
+            GaloisRing::new(BigInt::from(1) << 64, irreducible_polynomial(5).unwrap()).unwrap();
+        assert!(matches!(
+            Rmfe::new(&prime_degree, 4),
+            Err(Error::InvalidRingParameters(_))
+        ));
+        let small = GaloisRing::new(BigInt::from(8), irreducible_polynomial(4).unwrap()).unwrap();
+        assert!(matches!(
+            Rmfe::new(&small, 3),
+            Err(Error::InvalidRingParameters(_))
+        ));
+    }
+
+    #[test]
+    fn test_batched_multiplication_with_reencoding() {
+        let ring =
+            GaloisRing::new(BigInt::from(1) << 32, irreducible_polynomial(15).unwrap()).unwrap();
+        let narrow = GaloisRing::<u32>::from_bigint_ring(&ring).unwrap();
+        let rmfe = Rmfe::new(&narrow, 6).unwrap();
+        let (n, t) = (3, 2);
+        let inputs = [
+            [3u32, 5, 7, 9, 1, 0],
+            [11, 13, 17, 19, 1 << 16, 4],
+            [u32::MAX, 2, 1 << 31, 3, 1 << 16, 6],
+        ];
+        let shares: Vec<_> = inputs
+            .iter()
+            .map(|x| {
+                let encoded = rmfe.encode(x).unwrap();
+                narrow.shamir_secret_sharing(encoded, n, t).unwrap()
+            })
+            .collect();
+        let mut dealt = narrow.deal_triples(2, n, t).unwrap();
+        let pairs = rmfe.deal_reencoding_pairs(2, n, t).unwrap();
+
+        // Two batches of six Z/2^32 multiplications, one ring multiplication each
+        let mut product = shares[0].clone();
+        for (round, factor) in shares[1..].iter().enumerate() {
+            let triples: Vec<_> = dealt
+                .iter_mut()
+                .map(|party| party.take_triples(1).unwrap().remove(0))
+                .collect();
+            let raw = narrow.beaver_multiply(&product, factor, &triples).unwrap();
+            let round_pairs: Vec<_> = pairs.iter().map(|party| party[round].clone()).collect();
+            product = rmfe.reencode_shares(&raw, &round_pairs).unwrap();
+        }
+
+        let opened = narrow.reconstruct_secret(product[1..].to_vec(), t).unwrap();
+        let expected: Vec<u32> = (0..6)
+            .map(|i| inputs.iter().fold(1u32, |acc, x| acc.wrapping_mul(x[i])))
+            .collect();
+        assert_eq!(rmfe.decode(&opened), Ok(expected));
+    }
+}
@@ -0,0 +1,508 @@
+//! A compact, versioned binary format for ring parameters and shares.
+//!
+//! Every encoding starts with the magic bytes `GRSS`, a format version byte
+//! and a kind byte. Integers are unsigned LEB128 varints, big integers are a
+//! varint byte length followed by the big-endian magnitude without leading
+//! zeros, and polynomials are a varint coefficient count followed by their
+//! coefficients, lowest degree first.
+//!
+//! ```text
+//! ring:  magic version 0x00 p k irreducible
+//! share: magic version 0x01 p k irreducible threshold party_index epoch point value
+//! triples: magic version 0x02 p k irreducible threshold party_index point count (a b c)*
+//! ```
+//!
+//! A triples record stores one party's preprocessing; the triple shares are
+//! the values of its shares of `a`, `b` and `ab`, all at `point`.
+//!
+//! Version 1 shares have no epoch field and decode with epoch 0.
+//!
+//! Decoding is strict: non-minimal varints, leading zero bytes, trailing
+//! bytes and elements outside the ring are all rejected.
+
+use num_bigint::{BigInt, Sign};
+
+use crate::beaver::{Preprocessing, Triple};
+use crate::coefficient::Coefficient;
+use crate::error::{Error, Result};
+use crate::galois_ring::GaloisRing;
+use crate::polynomial::Polynomial;
+use crate::sharing::Share;
+
+/// The version written by this crate.
+pub const FORMAT_VERSION: u8 = 2;
+
+const MAGIC: &[u8; 4] = b"GRSS";
+const KIND_RING: u8 = 0;
+const KIND_SHARE: u8 = 1;
+const KIND_TRIPLES: u8 = 2;
+
+/// A share together with the metadata needed to use it on its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShareRecord<C: Coefficient = BigInt> {
+    /// Number of shares needed to reconstruct.
+    pub threshold: usize,
+    /// Index of the party holding the share.
+    pub party_index: usize,
+    /// Number of refreshes the share has gone through; shares combine only
+    /// within one epoch.
+    pub epoch: u64,
+    pub point: Polynomial<C>,
+    pub value: Polynomial<C>,
+}
+
+impl<C: Coefficient> ShareRecord<C> {
+    /// The share used by reconstruction.
+    pub fn share(&self) -> Share<C> {
+        Share::from_parts(self.point.clone(), self.value.clone())
+    }
+}
+
+impl<C: Coefficient> GaloisRing<C> {
+    /// Encode the ring parameters `p`, `k` and `h`.
+    pub fn encode_ring(&self) -> Vec<u8> {
+        let mut writer = Writer::new(KIND_RING);
+        self.write_parameters(&mut writer);
+        writer.bytes
+    }
+
+    /// Encode `record` with the parameters of this ring.
+    ///
+    /// Fails with [`Error::MismatchedRing`] if the point or value does not
+    /// belong to the ring.
+    pub fn encode_share(&self, record: &ShareRecord<C>) -> Result<Vec<u8>> {
+        self.validate_element(&record.point)?;
+        self.validate_element(&record.value)?;
+        let mut writer = Writer::new(KIND_SHARE);
+        self.write_parameters(&mut writer);
+        writer.put_varint(record.threshold as u64);
+        writer.put_varint(record.party_index as u64);
+        writer.put_varint(record.epoch);
+        writer.put_polynomial(&record.point.to_bigint());
+        writer.put_polynomial(&record.value.to_bigint());
+        Ok(writer.bytes)
+    }
+
+    /// Decode a share that must have been encoded for this ring.
+    ///
+    /// Fails with [`Error::MismatchedRing`] if the encoded ring parameters
+    /// differ from this ring's or the point or value does not belong to it.
+    pub fn decode_share(&self, bytes: &[u8]) -> Result<ShareRecord<C>> {
+        let mut reader = Reader::new(bytes, KIND_SHARE)?;
+        self.check_parameters(&mut reader)?;
+        let threshold = reader.usize()?;
+        let party_index = reader.usize()?;
+        let epoch = if reader.version >= 2 {
+            reader.varint()?
+        } else {
+            0
+        };
+        let point = reader.polynomial()?;
+        let value = reader.polynomial()?;
+        reader.finish()?;
+
+        let record = ShareRecord {
+            threshold,
+            party_index,
+            epoch,
+            point: self.checked_element(&point)?,
+            value: self.checked_element(&value)?,
+        };
+        Ok(record)
+    }
+
+    /// Encode one party's `preprocessing` with the parameters of this ring.
+    ///
+    /// Fails with [`Error::MismatchedPoint`] if a triple share is not at the
+    /// party's point and with [`Error::MismatchedRing`] if an element does not
+    /// belong to the ring.
+    pub fn encode_preprocessing(&self, preprocessing: &Preprocessing<C>) -> Result<Vec<u8>> {
+        self.validate_element(&preprocessing.point)?;
+        let mut writer = Writer::new(KIND_TRIPLES);
+        self.write_parameters(&mut writer);
+        writer.put_varint(preprocessing.threshold as u64);
+        writer.put_varint(preprocessing.party_index as u64);
+        writer.put_polynomial(&preprocessing.point.to_bigint());
+        writer.put_varint(preprocessing.triples.len() as u64);
+        for triple in &preprocessing.triples {
+            for share in [&triple.a, &triple.b, &triple.c] {
+                if share.point() != &preprocessing.point {
+                    return Err(Error::MismatchedPoint);
+                }
+                share.validate(self)?;
+                writer.put_polynomial(&share.value().to_bigint());
+            }
+        }
+        Ok(writer.bytes)
+    }
+
+    /// Decode preprocessing that must have been encoded for this ring.
+    ///
+    /// Fails like [`GaloisRing::decode_share`].
+    pub fn decode_preprocessing(&self, bytes: &[u8]) -> Result<Preprocessing<C>> {
+        let mut reader = Reader::new(bytes, KIND_TRIPLES)?;
+        self.check_parameters(&mut reader)?;
+        let threshold = reader.usize()?;
+        let party_index = reader.usize()?;
+        let point = self.checked_element(&reader.polynomial()?)?;
+        let count = reader.usize()?;
+        // Every triple takes at least three bytes, so this bounds the allocation
+        if count > reader.bytes.len() / 3 {
+            return Err(Error::InvalidEncoding("unexpected end of input".into()));
+        }
+        let mut triples = Vec::with_capacity(count);
+        for _ in 0..count {
+            let mut share = || -> Result<Share<C>> {
+                let value = self.checked_element(&reader.polynomial()?)?;
+                Ok(Share::from_parts(point.clone(), value))
+            };
+            triples.push(Triple {
+                a: share()?,
+                b: share()?,
+                c: share()?,
+            });
+        }
+        reader.finish()?;
+        Ok(Preprocessing {
+            threshold,
+            party_index,
+            point,
+            triples,
+        })
+    }
+
+    /// Read the ring parameters and check that they are this ring's.
+    fn check_parameters(&self, reader: &mut Reader) -> Result<()> {
+        let (prime, exponent, irreducible) = reader.parameters()?;
+        if prime != self.prime
+            || exponent != self.exponent
+            || irreducible != self.irreducible.to_bigint()
+        {
+            return Err(Error::MismatchedRing);
+        }
+        Ok(())
+    }
+
+    fn write_parameters(&self, writer: &mut Writer) {
+        writer.put_bigint(&self.prime);
+        writer.put_varint(u64::from(self.exponent));
+        writer.put_polynomial(&self.irreducible.to_bigint());
+    }
+
+    /// Convert a decoded element, rejecting unreduced coefficients instead of
+    /// silently reducing them.
+    fn checked_element(&self, poly: &Polynomial) -> Result<Polynomial<C>> {
+        if poly.coeffs.iter().any(|c| c >= &self.modulus) {
+            return Err(Error::MismatchedRing);
+        }
+        let elem = self.element_from_bigint(poly);
+        self.validate_element(&elem)?;
+        Ok(elem)
+    }
+}
+
+impl GaloisRing {
+    /// Decode and validate ring parameters written by [`GaloisRing::encode_ring`].
+    pub fn decode_ring(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(bytes, KIND_RING)?;
+        let (prime, exponent, irreducible) = reader.parameters()?;
+        reader.finish()?;
+        GaloisRing::from_prime_power(prime, exponent, irreducible)
+    }
+
+    /// Decode a share together with the ring it was encoded for and the
+    /// format version it was encoded with.
+    pub fn decode_share_and_ring(bytes: &[u8]) -> Result<(Self, ShareRecord, u8)> {
+        let mut reader = Reader::new(bytes, KIND_SHARE)?;
+        let (prime, exponent, irreducible) = reader.parameters()?;
+        let ring = GaloisRing::from_prime_power(prime, exponent, irreducible)?;
+        let record = ring.decode_share(bytes)?;
+        Ok((ring, record, reader.version))
+    }
+}
+
+/// Size of `poly` in this format, used to account for network traffic.
+pub(crate) fn encoded_polynomial_len<C: Coefficient>(poly: &Polynomial<C>) -> usize {
+    let mut writer = Writer { bytes: Vec::new() };
+    writer.put_polynomial(&poly.to_bigint());
+    writer.bytes.len()
+}
+
+struct Writer {
+    bytes: Vec<u8>,
+}
+
+impl Writer {
+    fn new(kind: u8) -> Self {
+        let mut bytes = MAGIC.to_vec();
+        bytes.push(FORMAT_VERSION);
+        bytes.push(kind);
+        Writer { bytes }
+    }
+
+    fn put_varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.bytes.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.bytes.push(value as u8);
+    }
+
+    fn put_bigint(&mut self, value: &BigInt) {
+        let magnitude = if value.sign() == Sign::NoSign {
+            Vec::new()
+        } else {
+            value.magnitude().to_bytes_be()
+        };
+        self.put_varint(magnitude.len() as u64);
+        self.bytes.extend_from_slice(&magnitude);
+    }
+
+    fn put_polynomial(&mut self, poly: &Polynomial) {
+        let poly = poly.trimmed_poly();
+        self.put_varint(poly.coeffs.len() as u64);
+        for coeff in &poly.coeffs {
+            self.put_bigint(coeff);
+        }
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    version: u8,
+}
+
+impl<'a> Reader<'a> {
+    /// Check the header and position the reader after it.
+    fn new(bytes: &'a [u8], kind: u8) -> Result<Self> {
+        let mut reader = Reader { bytes, version: 0 };
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(Error::InvalidEncoding("missing magic bytes".into()));
+        }
+        reader.version = reader.take(1)?[0];
+        if !(1..=FORMAT_VERSION).contains(&reader.version) {
+            return Err(Error::UnsupportedVersion(reader.version));
+        }
+        if reader.take(1)?[0] != kind {
+            return Err(Error::InvalidEncoding("unexpected record kind".into()));
+        }
+        Ok(reader)
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.bytes.len() < len {
+            return Err(Error::InvalidEncoding("unexpected end of input".into()));
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn varint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            let bits = u64::from(byte & 0x7f);
+            if bits << shift >> shift != bits {
+                break;
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                if byte == 0 && shift > 0 {
+                    return Err(Error::InvalidEncoding("non-minimal varint".into()));
+                }
+                return Ok(value);
+            }
+        }
+        Err(Error::InvalidEncoding("varint overflows 64 bits".into()))
+    }
+
+    fn usize(&mut self) -> Result<usize> {
+        usize::try_from(self.varint()?)
+            .map_err(|_| Error::InvalidEncoding("length does not fit in usize".into()))
+    }
+
+    fn bigint(&mut self) -> Result<BigInt> {
+        let len = self.usize()?;
+        let magnitude = self.take(len)?;
+        if magnitude.first() == Some(&0) {
+            return Err(Error::InvalidEncoding(
+                "integer has a leading zero byte".into(),
+            ));
+        }
+        Ok(BigInt::from_bytes_be(Sign::Plus, magnitude))
+    }
+
+    fn polynomial(&mut self) -> Result<Polynomial> {
+        let len = self.usize()?;
+        // Every coefficient takes at least one byte, so this bounds the allocation
+        if len > self.bytes.len() {
+            return Err(Error::InvalidEncoding("unexpected end of input".into()));
+        }
+        let coeffs = (0..len)
+            .map(|_| self.bigint())
+            .collect::<Result<Vec<_>>>()?;
+        if coeffs.last().is_some_and(|c| c.sign() == Sign::NoSign) {
+            return Err(Error::InvalidEncoding(
+                "polynomial has a trailing zero coefficient".into(),
+            ));
+        }
+        Ok(Polynomial::new(coeffs))
+    }
+
+    fn parameters(&mut self) -> Result<(BigInt, u32, Polynomial)> {
+        let prime = self.bigint()?;
+        let exponent = u32::try_from(self.varint()?)
+            .map_err(|_| Error::InvalidEncoding("exponent does not fit in u32".into()))?;
+        let irreducible = self.polynomial()?;
+        Ok((prime, exponent, irreducible))
+    }
+
+    fn finish(&self) -> Result<()> {
+        if !self.bytes.is_empty() {
+            return Err(Error::InvalidEncoding("trailing bytes".into()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::irreducible::irreducible_polynomial;
+    use crate::test_utils::ring_2_64_u64;
+
+    #[test]
+    fn test_share_round_trip() {
+        let wide = ring_2_64_u64();
+        let ring = wide.to_bigint_ring();
+        let secret = wide.random_ring_element();
+        let shares = wide.shamir_secret_sharing(secret.clone(), 5, 3).unwrap();
+
+        let encoded: Vec<Vec<u8>> = shares
+            .iter()
+            .enumerate()
+            .map(|(i, share)| {
+                let record = ShareRecord {
+                    threshold: 3,
+                    party_index: i,
+                    epoch: 0,
+                    point: share.point().clone(),
+                    value: share.value().clone(),
+                };
+                wide.encode_share(&record).unwrap()
+            })
+            .collect();
+
+        let records: Vec<ShareRecord<u64>> = encoded
+            .iter()
+            .map(|bytes| wide.decode_share(bytes).unwrap())
+            .collect();
+        assert_eq!(records[4].party_index, 4);
+        let decoded: Vec<_> = records[2..].iter().map(ShareRecord::share).collect();
+        assert_eq!(wide.reconstruct_secret(decoded, 3), Ok(secret.clone()));
+
+        // The same bytes decode with the BigInt backend, ring included
+        let (decoded_ring, record, version) =
+            GaloisRing::decode_share_and_ring(&encoded[0]).unwrap();
+        assert_eq!(decoded_ring.modulus(), ring.modulus());
+        assert_eq!(version, FORMAT_VERSION);
+        assert_eq!(record.value, shares[0].value().to_bigint());
+        assert_eq!(
+            GaloisRing::decode_ring(&ring.encode_ring())
+                .unwrap()
+                .irreducible(),
+            ring.irreducible()
+        );
+    }
+
+    #[test]
+    fn test_strict_decoding() {
+        let ring = GaloisRing::new(BigInt::from(8), irreducible_polynomial(3).unwrap()).unwrap();
+        let other = GaloisRing::new(BigInt::from(16), irreducible_polynomial(3).unwrap()).unwrap();
+        let (point, value) = ring
+            .shamir_secret_sharing(ring.random_ring_element(), 3, 2)
+            .unwrap()
+            .remove(0)
+            .into_parts();
+        let record = ShareRecord {
+            threshold: 2,
+            party_index: 0,
+            epoch: 3,
+            point,
+            value,
+        };
+        let bytes = ring.encode_share(&record).unwrap();
+
+        assert_eq!(ring.decode_share(&bytes), Ok(record.clone()));
+        assert_eq!(other.decode_share(&bytes), Err(Error::MismatchedRing));
+        assert!(matches!(
+            ring.decode_share(&bytes[..bytes.len() - 1]),
+            Err(Error::InvalidEncoding(_))
+        ));
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(matches!(
+            ring.decode_share(&trailing),
+            Err(Error::InvalidEncoding(_))
+        ));
+        let mut future = bytes.clone();
+        future[4] = FORMAT_VERSION + 1;
+        assert_eq!(
+            ring.decode_share(&future),
+            Err(Error::UnsupportedVersion(FORMAT_VERSION + 1))
+        );
+        assert!(matches!(
+            ring.decode_share(&ring.encode_ring()),
+            Err(Error::InvalidEncoding(_))
+        ));
+
+        // Version 1 shares predate epochs
+        let mut writer = Writer::new(KIND_SHARE);
+        ring.write_parameters(&mut writer);
+        writer.put_varint(2);
+        writer.put_varint(0);
+        writer.put_polynomial(&record.point);
+        writer.put_polynomial(&record.value);
+        writer.bytes[4] = 1;
+        assert_eq!(
+            ring.decode_share(&writer.bytes),
+            Ok(ShareRecord {
+                epoch: 0,
+                ..record.clone()
+            })
+        );
+        let (_, _, version) = GaloisRing::decode_share_and_ring(&writer.bytes).unwrap();
+        assert_eq!(version, 1);
+    }
+
+    #[test]
+    fn test_preprocessing_round_trip() {
+        let ring =
+            GaloisRing::new(BigInt::from(1) << 64, irreducible_polynomial(3).unwrap()).unwrap();
+        let wide = GaloisRing::<u64>::from_bigint_ring(&ring).unwrap();
+        let dealt = wide.deal_triples(4, 3, 2).unwrap();
+
+        let files: Vec<_> = dealt
+            .iter()
+            .map(|party| wide.encode_preprocessing(party).unwrap())
+            .collect();
+        let decoded: Vec<_> = files
+            .iter()
+            .map(|bytes| wide.decode_preprocessing(bytes).unwrap())
+            .collect();
+        assert_eq!(decoded, dealt);
+        assert_eq!(ring.decode_preprocessing(&files[1]).unwrap().party_index, 1);
+        assert!(matches!(
+            wide.decode_preprocessing(&files[0][..files[0].len() - 1]),
+            Err(Error::InvalidEncoding(_))
+        ));
+
+        let mut moved = dealt[0].clone();
+        moved.triples[3].b = dealt[1].triples[3].b.clone();
+        assert_eq!(
+            wide.encode_preprocessing(&moved),
+            Err(Error::MismatchedPoint)
+        );
+    }
+}
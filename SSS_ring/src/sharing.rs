@@ -0,0 +1,548 @@
+use num_bigint::BigInt;
+use rand::{CryptoRng, RngCore};
+
+use crate::coefficient::Coefficient;
+use crate::error::{Error, Result};
+use crate::galois_ring::GaloisRing;
+use crate::polynomial::Polynomial;
+
+/// A share: the evaluation point and the value of the sharing polynomial there.
+///
+/// Shares come out of the sharing functions or [`Share::new`], which checks
+/// both elements against a ring, and are combined with the operations of
+/// [`crate::linear`], which check that the shares match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Share<C: Coefficient = BigInt> {
+    point: Polynomial<C>,
+    value: Polynomial<C>,
+}
+
+impl<C: Coefficient> Share<C> {
+    /// The share with value `value` at `point`.
+    ///
+    /// Fails with [`Error::MismatchedRing`] if either is not a reduced element
+    /// of `ring`.
+    pub fn new(ring: &GaloisRing<C>, point: Polynomial<C>, value: Polynomial<C>) -> Result<Self> {
+        ring.validate_element(&point)?;
+        ring.validate_element(&value)?;
+        Ok(Share { point, value })
+    }
+
+    /// A share of elements the caller has already checked.
+    pub(crate) fn from_parts(point: Polynomial<C>, value: Polynomial<C>) -> Self {
+        Share { point, value }
+    }
+
+    /// The evaluation point.
+    pub fn point(&self) -> &Polynomial<C> {
+        &self.point
+    }
+
+    /// The value of the sharing polynomial at the point.
+    pub fn value(&self) -> &Polynomial<C> {
+        &self.value
+    }
+
+    /// The point and the value.
+    pub fn into_parts(self) -> (Polynomial<C>, Polynomial<C>) {
+        (self.point, self.value)
+    }
+}
+
+impl<C: Coefficient> GaloisRing<C> {
+    /// A uniformly random ring element.
+    pub fn random_ring_element(&self) -> Polynomial<C> {
+        self.random_ring_element_with_rng(&mut rand::thread_rng())
+    }
+
+    /// A uniformly random ring element drawn from `rng`.
+    ///
+    /// Every coefficient backend consumes `rng` identically, so rings that
+    /// differ only in their backend produce the same element.
+    pub fn random_ring_element_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Polynomial<C> {
+        let d = self.irreducible.degree();
+        let coeffs: Vec<C> = (0..d)
+            .map(|_| C::random(rng, &self.coefficient_modulus))
+            .collect();
+        Polynomial::new(coeffs)
+    }
+
+    /// Coefficients of a random sharing polynomial of degree `t - 1` whose
+    /// constant term is `secret`.
+    pub fn generate_random_polynomial_with_secret(
+        &self,
+        secret: Polynomial<C>,
+        t: usize,
+    ) -> Vec<Polynomial<C>> {
+        self.generate_random_polynomial_with_secret_with_rng(secret, t, &mut rand::thread_rng())
+    }
+
+    /// [`GaloisRing::generate_random_polynomial_with_secret`] with randomness from `rng`.
+    pub fn generate_random_polynomial_with_secret_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        secret: Polynomial<C>,
+        t: usize,
+        rng: &mut R,
+    ) -> Vec<Polynomial<C>> {
+        let mut rand_poly: Vec<Polynomial<C>> = vec![secret];
+        for _ in 1..t {
+            rand_poly.push(self.random_ring_element_with_rng(rng));
+        }
+        rand_poly
+    }
+
+    /// Evaluate the polynomial with ring coefficients `rand_polynomial_ring` at
+    /// `point` by Horner's rule.
+    pub fn evaluate_polynomial(
+        &self,
+        point: &Polynomial<C>,
+        rand_polynomial_ring: &[Polynomial<C>],
+    ) -> Polynomial<C> {
+        let mut eval_poly = GaloisRing::zero();
+        for coeff in rand_polynomial_ring.iter().rev() {
+            eval_poly = self.add_ring(&self.mul_ring(&eval_poly, point), coeff);
+        }
+        eval_poly
+    }
+
+    /// Share `secret` among `number_of_parties` parties with threshold `t`, one
+    /// exceptional point each.
+    ///
+    /// Any `t` of the shares reconstruct the secret. Fails unless `secret`
+    /// belongs to the ring and `1 <= t <= number_of_parties <= |exceptional set|`.
+    pub fn shamir_secret_sharing(
+        &self,
+        secret: Polynomial<C>,
+        number_of_parties: usize,
+        t: usize,
+    ) -> Result<Vec<Share<C>>> {
+        self.shamir_secret_sharing_with_rng(secret, number_of_parties, t, &mut rand::thread_rng())
+    }
+
+    /// [`GaloisRing::shamir_secret_sharing`] with randomness from `rng`.
+    pub fn shamir_secret_sharing_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        secret: Polynomial<C>,
+        number_of_parties: usize,
+        t: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Share<C>>> {
+        check_threshold(t, number_of_parties)?;
+        self.validate_element(&secret)?;
+        let random_polynomial_ring =
+            self.generate_random_polynomial_with_secret_with_rng(secret, t, rng);
+        self.shamir_secret_sharing_non_random(random_polynomial_ring, number_of_parties)
+    }
+
+    /// Share `secret` with threshold `t`, evaluating at caller-supplied points.
+    ///
+    /// Fails unless `1 <= t <= points.len()` and the points are distinct and
+    /// together with zero form an exceptional set.
+    pub fn shamir_secret_sharing_at_points(
+        &self,
+        secret: Polynomial<C>,
+        points: Vec<Polynomial<C>>,
+        t: usize,
+    ) -> Result<Vec<Share<C>>> {
+        self.shamir_secret_sharing_at_points_with_rng(secret, points, t, &mut rand::thread_rng())
+    }
+
+    /// [`GaloisRing::shamir_secret_sharing_at_points`] with randomness from `rng`.
+    pub fn shamir_secret_sharing_at_points_with_rng<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        secret: Polynomial<C>,
+        points: Vec<Polynomial<C>>,
+        t: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Share<C>>> {
+        check_threshold(t, points.len())?;
+        self.validate_element(&secret)?;
+        self.validate_evaluation_points(&points)?;
+        let random_polynomial_ring =
+            self.generate_random_polynomial_with_secret_with_rng(secret, t, rng);
+        Ok(self.evaluate_at_points(&random_polynomial_ring, points))
+    }
+
+    /// Share using caller-chosen sharing polynomial coefficients.
+    ///
+    /// The threshold is the number of coefficients in `poly_vec`.
+    pub fn shamir_secret_sharing_non_random(
+        &self,
+        poly_vec: Vec<Polynomial<C>>,
+        number_of_parties: usize,
+    ) -> Result<Vec<Share<C>>> {
+        check_threshold(poly_vec.len(), number_of_parties)?;
+        for coeff in &poly_vec {
+            self.validate_element(coeff)?;
+        }
+        let points = self.exceptional_points(number_of_parties)?;
+        Ok(self.evaluate_at_points(&poly_vec, points))
+    }
+
+    /// Check that `points` are distinct ring elements that together with zero
+    /// form an exceptional set.
+    pub(crate) fn validate_evaluation_points(&self, points: &[Polynomial<C>]) -> Result<()> {
+        for (i, point) in points.iter().enumerate() {
+            self.validate_element(point)?;
+            if points[..i].contains(point) {
+                return Err(Error::DuplicatePoint);
+            }
+        }
+        if !self.is_valid_evaluation_set(points) {
+            return Err(Error::NotExceptional);
+        }
+        Ok(())
+    }
+
+    fn evaluate_at_points(
+        &self,
+        poly_vec: &[Polynomial<C>],
+        points: Vec<Polynomial<C>>,
+    ) -> Vec<Share<C>> {
+        points
+            .into_iter()
+            .map(|point| {
+                let evaluated_element = self.evaluate_polynomial(&point, poly_vec);
+                Share::from_parts(point, evaluated_element)
+            })
+            .collect()
+    }
+}
+
+impl<C: Coefficient> GaloisRing<C> {
+    /// Share each of `secrets` among `number_of_parties` parties with threshold
+    /// `t`, reusing one [`SharingPlan`] for all of them.
+    ///
+    /// Returns the shares of each secret in order. Fails on the same inputs as
+    /// [`GaloisRing::shamir_secret_sharing`].
+    pub fn shamir_secret_sharing_many(
+        &self,
+        secrets: &[Polynomial<C>],
+        number_of_parties: usize,
+        t: usize,
+    ) -> Result<Vec<Vec<Share<C>>>> {
+        let points = self.exceptional_points(number_of_parties)?;
+        SharingPlan::new(self, points, t)?.share_many(secrets, &mut rand::thread_rng())
+    }
+}
+
+/// Powers `x^0, ..., x^(t-1)` of a fixed set of evaluation points, computed once.
+///
+/// Evaluating a sharing polynomial at all points is then one inner product
+/// per point followed by one reduction modulo `h`, which pays off when many
+/// secrets are shared against the same points.
+#[derive(Debug, Clone)]
+pub struct SharingPlan<C: Coefficient = BigInt> {
+    ring: GaloisRing<C>,
+    threshold: usize,
+    points: Vec<Polynomial<C>>,
+    powers: Vec<Vec<Polynomial<C>>>,
+}
+
+impl<C: Coefficient> SharingPlan<C> {
+    /// Plan threshold-`t` sharings at `points`.
+    ///
+    /// Fails unless `1 <= t <= points.len()` and the points are distinct and
+    /// together with zero form an exceptional set.
+    pub fn new(ring: &GaloisRing<C>, points: Vec<Polynomial<C>>, t: usize) -> Result<Self> {
+        check_threshold(t, points.len())?;
+        ring.validate_evaluation_points(&points)?;
+        let powers = points
+            .iter()
+            .map(|point| {
+                let mut powers = Vec::with_capacity(t);
+                powers.push(GaloisRing::one());
+                for i in 1..t {
+                    powers.push(ring.mul_ring(&powers[i - 1], point));
+                }
+                powers
+            })
+            .collect();
+        Ok(SharingPlan {
+            ring: ring.clone(),
+            threshold: t,
+            points,
+            powers,
+        })
+    }
+
+    /// The evaluation points, in the order shares are returned.
+    pub fn points(&self) -> &[Polynomial<C>] {
+        &self.points
+    }
+
+    /// Evaluate the polynomial with coefficients `poly_vec` at every point.
+    ///
+    /// Fails with [`Error::TooManyCoefficients`] if there are more than `t`
+    /// coefficients.
+    pub fn evaluate(&self, poly_vec: &[Polynomial<C>]) -> Result<Vec<Polynomial<C>>> {
+        if poly_vec.len() > self.threshold {
+            return Err(Error::TooManyCoefficients {
+                threshold: self.threshold,
+                coefficients: poly_vec.len(),
+            });
+        }
+        Ok(self
+            .powers
+            .iter()
+            .map(|powers| {
+                let mut sum = GaloisRing::zero();
+                for (coeff, power) in poly_vec.iter().zip(powers) {
+                    sum = self.ring.add(&sum, &self.ring.mul(coeff, power));
+                }
+                self.ring.reduce(&sum)
+            })
+            .collect())
+    }
+
+    /// Share `secret` with a fresh random sharing polynomial.
+    pub fn share<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        secret: Polynomial<C>,
+        rng: &mut R,
+    ) -> Result<Vec<Share<C>>> {
+        self.ring.validate_element(&secret)?;
+        let poly_vec =
+            self.ring
+                .generate_random_polynomial_with_secret_with_rng(secret, self.threshold, rng);
+        let values = self.evaluate(&poly_vec)?;
+        Ok(self
+            .points
+            .iter()
+            .cloned()
+            .zip(values)
+            .map(|(point, value)| Share::from_parts(point, value))
+            .collect())
+    }
+
+    /// Share each of `secrets` independently, returning the shares of each in order.
+    pub fn share_many<R: RngCore + CryptoRng + ?Sized>(
+        &self,
+        secrets: &[Polynomial<C>],
+        rng: &mut R,
+    ) -> Result<Vec<Vec<Share<C>>>> {
+        secrets
+            .iter()
+            .map(|secret| self.share(secret.clone(), rng))
+            .collect()
+    }
+}
+
+/// Check `1 <= t <= number_of_parties`.
+pub(crate) fn check_threshold(t: usize, number_of_parties: usize) -> Result<()> {
+    if t == 0 || t > number_of_parties {
+        return Err(Error::InvalidThreshold {
+            threshold: t,
+            parties: number_of_parties,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::irreducible::irreducible_polynomial;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_non_random_sharing_evaluates_polynomial() {
+        let ring = GaloisRing::new(BigInt::from(7), irreducible_polynomial(3).unwrap()).unwrap();
+        let secret = Polynomial::new(vec![BigInt::from(6), BigInt::from(4)]);
+        let a1 = Polynomial::new(vec![BigInt::from(5), BigInt::from(6)]);
+        let a2 = Polynomial::new(vec![BigInt::from(0), BigInt::from(5)]);
+
+        let shares = ring
+            .shamir_secret_sharing_non_random(vec![secret.clone(), a1.clone(), a2.clone()], 3)
+            .unwrap();
+
+        // The first exceptional point is 1, where the polynomial evaluates to the sum of its coefficients
+        assert_eq!(shares[0].point(), &GaloisRing::one());
+        assert_eq!(
+            shares[0].value(),
+            &ring.add_ring(&ring.add_ring(&secret, &a1), &a2)
+        );
+    }
+
+    #[test]
+    fn test_threshold_independent_of_degree() {
+        let ring = GaloisRing::new(BigInt::from(8), irreducible_polynomial(2).unwrap()).unwrap();
+        let secret = ring.random_ring_element();
+
+        // d = 2 but the sharing polynomial has degree t - 1 = 3
+        let poly = ring.generate_random_polynomial_with_secret(secret, 4);
+        assert_eq!(poly.len(), 4);
+
+        let shares = ring
+            .shamir_secret_sharing(ring.random_ring_element(), 3, 1)
+            .unwrap();
+        assert!(shares.windows(2).all(|w| w[0].value() == w[1].value()));
+    }
+
+    #[test]
+    fn test_sharing_at_points_with_rng() {
+        let ring = GaloisRing::from_parameters(BigInt::from(5), 4, 2).unwrap();
+        let secret = ring.random_ring_element();
+        let points = ring.exceptional_points(6).unwrap();
+
+        // The default points are the first exceptional points, so the shares agree
+        let at_points = ring
+            .shamir_secret_sharing_at_points_with_rng(
+                secret.clone(),
+                points,
+                3,
+                &mut StdRng::seed_from_u64(9),
+            )
+            .unwrap();
+        let default = ring
+            .shamir_secret_sharing_with_rng(secret, 6, 3, &mut StdRng::seed_from_u64(9))
+            .unwrap();
+        assert_eq!(at_points, default);
+    }
+
+    #[test]
+    fn test_threshold_above_party_count() {
+        let ring = GaloisRing::new(BigInt::from(7), irreducible_polynomial(3).unwrap()).unwrap();
+        assert_eq!(
+            ring.shamir_secret_sharing(ring.random_ring_element(), 3, 4),
+            Err(Error::InvalidThreshold {
+                threshold: 4,
+                parties: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_too_many_parties() {
+        let ring = GaloisRing::new(BigInt::from(8), irreducible_polynomial(3).unwrap()).unwrap();
+        assert_eq!(
+            ring.shamir_secret_sharing(ring.random_ring_element(), 8, 2),
+            Err(Error::TooManyParties {
+                requested: 8,
+                available: BigInt::from(7)
+            })
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_caller_points() {
+        let ring = GaloisRing::new(BigInt::from(8), irreducible_polynomial(3).unwrap()).unwrap();
+        let one = Polynomial::constant(BigInt::from(1));
+        let three = Polynomial::constant(BigInt::from(3));
+
+        let secret = ring.random_ring_element();
+        assert_eq!(
+            ring.shamir_secret_sharing_at_points(secret.clone(), vec![one.clone(), three], 2),
+            Err(Error::NotExceptional)
+        );
+        assert_eq!(
+            ring.shamir_secret_sharing_at_points(secret.clone(), vec![one.clone(), one], 2),
+            Err(Error::DuplicatePoint)
+        );
+        let foreign_secret = Polynomial::constant(BigInt::from(8));
+        assert_eq!(
+            ring.shamir_secret_sharing(foreign_secret, 3, 2),
+            Err(Error::MismatchedRing)
+        );
+    }
+
+    #[test]
+    fn test_fixed_width_backends_match_bigint_shares() {
+        let irreducible = irreducible_polynomial(4).unwrap();
+        for bits in [64, 32, 20] {
+            let ring = GaloisRing::new(BigInt::from(1) << bits, irreducible.clone()).unwrap();
+            let secret = ring.random_ring_element();
+
+            let expected = ring
+                .shamir_secret_sharing_with_rng(secret.clone(), 6, 4, &mut StdRng::seed_from_u64(1))
+                .unwrap();
+
+            let wide = GaloisRing::<u64>::from_bigint_ring(&ring).unwrap();
+            let shares = wide
+                .shamir_secret_sharing_with_rng(
+                    wide.element_from_bigint(&secret),
+                    6,
+                    4,
+                    &mut StdRng::seed_from_u64(1),
+                )
+                .unwrap();
+            assert!(shares
+                .iter()
+                .zip(&expected)
+                .all(|(share, e)| share.point().to_bigint() == *e.point()
+                    && share.value().to_bigint() == *e.value()));
+            assert_eq!(
+                wide.reconstruct_secret(shares[2..].to_vec(), 4)
+                    .unwrap()
+                    .to_bigint(),
+                secret
+            );
+
+            if bits <= 32 {
+                let narrow = GaloisRing::<u32>::from_bigint_ring(&ring).unwrap();
+                let shares = narrow
+                    .shamir_secret_sharing_with_rng(
+                        narrow.element_from_bigint(&secret),
+                        6,
+                        4,
+                        &mut StdRng::seed_from_u64(1),
+                    )
+                    .unwrap();
+                assert_eq!(shares[5].value().to_bigint(), *expected[5].value());
+            } else {
+                assert!(GaloisRing::<u32>::from_bigint_ring(&ring).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_horner_and_batched_evaluation_agree() {
+        let ring = GaloisRing::from_parameters(BigInt::from(3), 3, 3).unwrap();
+        let mut poly: Vec<_> = (0..5).map(|_| ring.random_ring_element()).collect();
+        let points = ring.exceptional_points(9).unwrap();
+        let plan = SharingPlan::new(&ring, points.clone(), 5).unwrap();
+
+        let batched = plan.evaluate(&poly).unwrap();
+        for (point, value) in points.iter().zip(&batched) {
+            let naive = poly
+                .iter()
+                .enumerate()
+                .fold(GaloisRing::zero(), |acc, (i, c)| {
+                    ring.add_ring(&acc, &ring.mul_ring(c, &ring.power_in_ring(point, i)))
+                });
+            assert_eq!(ring.evaluate_polynomial(point, &poly), naive);
+            assert_eq!(*value, naive);
+        }
+
+        poly.push(GaloisRing::one());
+        assert_eq!(
+            plan.evaluate(&poly),
+            Err(Error::TooManyCoefficients {
+                threshold: 5,
+                coefficients: 6
+            })
+        );
+    }
+
+    #[test]
+    fn test_share_many_secrets() {
+        let ring =
+            GaloisRing::new(BigInt::from(1) << 32, irreducible_polynomial(3).unwrap()).unwrap();
+        let narrow = GaloisRing::<u32>::from_bigint_ring(&ring).unwrap();
+        let secrets: Vec<_> = (0..4).map(|_| narrow.random_ring_element()).collect();
+
+        let sharings = narrow.shamir_secret_sharing_many(&secrets, 5, 3).unwrap();
+
+        assert_eq!(sharings.len(), 4);
+        for (secret, shares) in secrets.iter().zip(sharings) {
+            assert_eq!(
+                narrow.reconstruct_secret(shares[2..].to_vec(), 3),
+                Ok(secret.clone())
+            );
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! Fixtures shared by the unit tests.
+
+use num_bigint::BigInt;
+
+use crate::galois_ring::{GaloisRing, GaloisRing64};
+use crate::irreducible::irreducible_polynomial;
+
+/// `GR(2^64, 4)`, the ring most tests share secrets over.
+pub(crate) fn ring_2_64() -> GaloisRing {
+    GaloisRing::new(BigInt::from(1) << 64, irreducible_polynomial(4).unwrap()).unwrap()
+}
+
+/// [`ring_2_64`] with `u64` coefficients.
+pub(crate) fn ring_2_64_u64() -> GaloisRing64 {
+    GaloisRing64::from_bigint_ring(&ring_2_64()).unwrap()
+}